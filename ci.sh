@@ -3,5 +3,10 @@
 set -euxo pipefail
 
 cargo build
+rustup target add thumbv7em-none-eabihf
+cargo build -p ters --target thumbv7em-none-eabihf
+cargo build -p ters-no-std --target thumbv7em-none-eabihf
 cargo test
+cargo test --all-features
 cargo clippy -- --deny warnings
+cargo clippy --all-features -- --deny warnings
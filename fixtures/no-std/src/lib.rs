@@ -0,0 +1,53 @@
+//! A `#![no_std]` crate using the flavors of `#[ters]` which only need `core`,
+//! built for a bare-metal target in CI.
+
+#![no_std]
+
+use core::sync::atomic::AtomicU32;
+
+use ters::ters;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum State {
+    Idle,
+    Running { ticks: u32 },
+    Halted,
+}
+
+#[ters(constructor, generate_mask, generate_update_fn)]
+pub struct Flags {
+    #[get(copy)]
+    #[set]
+    pub ready: bool,
+    #[get(copy)]
+    #[set]
+    pub error: bool,
+}
+
+#[ters(constructor)]
+pub struct Device {
+    #[get]
+    #[get(mut)]
+    #[get(field_name_str)]
+    #[set]
+    id: u8,
+    #[get(copy)]
+    #[get(discriminant)]
+    #[get(matches = State::Running { .. }, name = "is_running")]
+    #[set]
+    state: State,
+    #[get(ok)]
+    #[get(err)]
+    #[get(is_ok)]
+    #[set]
+    last: Result<u16, u8>,
+    #[get(clone)]
+    #[get(opaque, name = "duty_any")]
+    #[set(bounded = "0..=100")]
+    duty: u8,
+    #[get(zip_adjacent)]
+    x: i16,
+    y: i16,
+    #[set(atomic_fetch_update)]
+    ticks: AtomicU32,
+}
@@ -0,0 +1,243 @@
+use proc_macro2::Span;
+use syn::{
+    meta::ParseNestedMeta, parse_quote, punctuated::Punctuated, spanned::Spanned, Ident, LitInt,
+    LitStr, Token, Type, Visibility,
+};
+
+use crate::{case::Case, names::parse_vis};
+
+/// Options accepted by `#[ters(...)]`.
+#[derive(Default)]
+pub struct Args {
+    /// Copy lint attributes from fields onto their accessors.
+    pub propagate_lints: bool,
+    /// Report the generated methods in a compile-time warning, spanned to the `info` option.
+    pub info: Option<Span>,
+    /// Reject accessors declared more visible than the struct itself.
+    pub visibility_check: bool,
+    /// Generate a `{Struct}Fields` struct the fields with getters can be moved into.
+    pub into_fields: Option<Span>,
+    /// Re-emit the struct without generating anything, only removing the field attributes.
+    pub passthrough: bool,
+    /// Generate a `new` constructor taking every field, spanned to the option.
+    pub constructor: Option<Span>,
+    /// Reject accessors whose names shadow methods of standard traits.
+    pub conflict_check: bool,
+    /// The opted-into version of the macro's defaults, `1` if unspecified.
+    pub version: Option<u8>,
+    /// Replaces the `set_` prefix of setter names, possibly with nothing.
+    pub setter_prefix: Option<String>,
+    /// Fields given a getter as if annotated with `#[get]`.
+    pub get: Vec<Ident>,
+    /// Fields given a setter as if annotated with `#[set]`.
+    pub set: Vec<Ident>,
+    /// Give every field without a `#[get]` one, spanned to the `get` option.
+    pub get_all: Option<Span>,
+    /// Give every field without a `#[set]` one, spanned to the `set` option.
+    pub set_all: Option<Span>,
+    /// Fields left out of `get_all` and `set_all`.
+    pub exclude: Vec<Ident>,
+    /// Generate a `builder` associated function returning `{Struct}Builder::default()`, spanned to the option.
+    pub generate_builder_fn: Option<Span>,
+    /// Generate a `Display` impl showing the fields which have getters.
+    pub impl_display: bool,
+    /// Leave `Option` fields out of the constructor, initializing them to `None`.
+    pub skip_none: Option<Span>,
+    /// Generate a `{Struct}Field` enum naming the fields with getters, and an `Index` impl for it.
+    pub generate_index: Option<Span>,
+    /// Generate `to_u8` and `from_u8` methods packing the `bool` fields into bits.
+    pub generate_mask: Option<Span>,
+    /// Generate a `TryFrom` impl for a fieldless enum from the given integer type, `u32` by default.
+    pub try_from_int: Option<(Span, Type)>,
+    /// Generate an `update` method cloning the fields with setters from another instance.
+    pub generate_update_fn: Option<Span>,
+    /// Leave out the `Getter for` and `Setter for` headings of the accessors' documentation.
+    pub no_docs: bool,
+    /// Generate a `{Struct}Snapshot` struct of clones of the fields with getters, and a `snapshot` method.
+    pub generate_snapshot: Option<Span>,
+    /// Generate a `{Struct}Diff` struct of the changed fields with getters, and a `diff` method.
+    pub generate_diff: Option<Span>,
+    /// Generate a `merge` method applying a `{Struct}Diff`, alongside `generate_diff`.
+    pub generate_merge: Option<Span>,
+    /// Generate a `set_from_env` method parsing the fields with setters from environment variables.
+    pub generate_setters_from_env: Option<Span>,
+    /// Generate a `{Struct}SerdeAccessors` trait serializing the fields with getters to JSON values.
+    pub generate_serde_accessor_trait: bool,
+    /// Generate `From` impls converting a tuple struct to and from a tuple of its fields.
+    pub impl_from_tuple: Option<Span>,
+    /// Leave `PhantomData` fields out of `get_all` and `set_all`, spanned to the option.
+    pub phantom_skip: Option<Span>,
+    /// The visibility of accessors not given a `vis`.
+    pub vis: Option<Visibility>,
+    /// The case generated method names are converted to, unless given a `name`.
+    pub rename_all: Option<Case>,
+}
+
+impl Args {
+    pub fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        if meta.path.is_ident("propagate_lints") {
+            self.propagate_lints = true;
+            Ok(())
+        } else if meta.path.is_ident("visibility_check") {
+            self.visibility_check = true;
+            Ok(())
+        } else if meta.path.is_ident("into_fields") {
+            self.into_fields = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("passthrough") {
+            self.passthrough = true;
+            Ok(())
+        } else if meta.path.is_ident("constructor") {
+            self.constructor = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("conflict_check") {
+            self.conflict_check = true;
+            Ok(())
+        } else if meta.path.is_ident("generate_builder_fn") {
+            self.generate_builder_fn = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("impl_display") {
+            self.impl_display = true;
+            Ok(())
+        } else if meta.path.is_ident("generate_index") {
+            self.generate_index = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("generate_mask") {
+            self.generate_mask = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("try_from_int") {
+            let ty = if meta.input.peek(Token![=]) {
+                meta.value()?.parse()?
+            } else {
+                parse_quote!(u32)
+            };
+
+            self.try_from_int = Some((meta.path.span(), ty));
+            Ok(())
+        } else if meta.path.is_ident("generate_update_fn") {
+            self.generate_update_fn = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("no_docs") {
+            self.no_docs = true;
+            Ok(())
+        } else if meta.path.is_ident("generate_snapshot") {
+            self.generate_snapshot = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("generate_diff") {
+            self.generate_diff = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("generate_merge") {
+            self.generate_merge = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("generate_setters_from_env") {
+            self.generate_setters_from_env = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("generate_serde_accessor_trait") {
+            if !cfg!(feature = "serde") {
+                return Err(meta.error(
+                    "`generate_serde_accessor_trait` requires the `serde` feature of `ters`",
+                ));
+            }
+
+            self.generate_serde_accessor_trait = true;
+            Ok(())
+        } else if meta.path.is_ident("impl_from_tuple") {
+            self.impl_from_tuple = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("phantom_skip") {
+            self.phantom_skip = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("skip_none") {
+            self.skip_none = Some(meta.path.span());
+            Ok(())
+        } else if meta.path.is_ident("version") {
+            if self.version.is_some() {
+                return Err(meta.error("duplicate `version` option"));
+            }
+
+            let lit = meta.value()?.parse::<LitInt>()?;
+            match lit.base10_parse()? {
+                version @ (1 | 2) => self.version = Some(version),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        lit,
+                        "unsupported `version`; expected `1` or `2`",
+                    ))
+                }
+            }
+            Ok(())
+        } else if meta.path.is_ident("setter_prefix") {
+            if self.setter_prefix.is_some() {
+                return Err(meta.error("duplicate `setter_prefix` option"));
+            }
+
+            let lit = meta.value()?.parse::<LitStr>()?;
+            let prefix = lit.value();
+
+            // the prefix must form an identifier with any field name
+            if syn::parse_str::<Ident>(&format!("{prefix}field")).is_err() {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    format!("`{prefix}` is not a valid method prefix"),
+                ));
+            }
+
+            self.setter_prefix = Some(prefix);
+            Ok(())
+        } else if meta.path.is_ident("vis") {
+            if self.vis.is_some() {
+                return Err(meta.error("duplicate `vis` option"));
+            }
+
+            self.vis = Some(parse_vis(meta.value()?)?);
+            Ok(())
+        } else if meta.path.is_ident("rename_all") {
+            if self.rename_all.is_some() {
+                return Err(meta.error("duplicate `rename_all` option"));
+            }
+
+            self.rename_all = Some(Case::parse(&meta.value()?.parse()?)?);
+            Ok(())
+        } else if meta.path.is_ident("get") {
+            if meta.input.peek(Token![=]) {
+                self.get.extend(parse_field_list(&meta)?);
+            } else {
+                self.get_all = Some(meta.path.span());
+            }
+            Ok(())
+        } else if meta.path.is_ident("set") {
+            if meta.input.peek(Token![=]) {
+                self.set.extend(parse_field_list(&meta)?);
+            } else {
+                self.set_all = Some(meta.path.span());
+            }
+            Ok(())
+        } else if meta.path.is_ident("exclude") {
+            self.exclude.extend(parse_field_list(&meta)?);
+            Ok(())
+        } else if meta.path.is_ident("info") {
+            self.info = Some(meta.path.span());
+            Ok(())
+        } else {
+            Err(meta.error("unrecognized ters option"))
+        }
+    }
+
+    /// The visibility of accessors not given a `vis`, if it differs from `pub`.
+    ///
+    /// Since `version = 2`, accessors are private by default.
+    pub fn default_vis(&self) -> Option<Visibility> {
+        self.vis
+            .clone()
+            .or_else(|| (self.version == Some(2)).then_some(Visibility::Inherited))
+    }
+}
+
+/// Parse a bracketed list of field names, as in `get = [a, b]`.
+fn parse_field_list(meta: &ParseNestedMeta) -> syn::Result<Punctuated<Ident, Token![,]>> {
+    let input = meta.value()?;
+    let content;
+    syn::bracketed!(content in input);
+
+    Punctuated::parse_terminated(&content)
+}
@@ -0,0 +1,16 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote_spanned;
+use syn::Type;
+
+/// Assert `ty` implements `bound` before the generated code relies on it.
+///
+/// An unmet bound is reported against the field's type and `span` (the flavor which
+/// requires it), naming the trait, instead of deep inside the generated method.
+pub fn assert_bound(ty: &Type, bound: TokenStream, span: Span) -> TokenStream {
+    quote_spanned! {span=>
+        {
+            fn field_must_implement<T: ?::core::marker::Sized + #bound>() {}
+            field_must_implement::<#ty>();
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use syn::{ext::IdentExt, Ident, LitStr};
+
+/// A naming convention selected by `#[ters(rename_all = "...")]` for generated methods.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Case {
+    Snake,
+    Camel,
+    Pascal,
+}
+
+impl Case {
+    pub fn parse(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "snake_case" => Ok(Self::Snake),
+            "camelCase" => Ok(Self::Camel),
+            "PascalCase" => Ok(Self::Pascal),
+            _ => Err(syn::Error::new_spanned(
+                lit,
+                "unsupported `rename_all`; expected `snake_case`, `camelCase`, or `PascalCase`",
+            )),
+        }
+    }
+
+    /// Convert a snake case method name to this case, keeping any leading underscores.
+    pub fn apply(self, ident: &Ident) -> Ident {
+        if self == Self::Snake {
+            return ident.clone();
+        }
+
+        let name = ident.unraw().to_string();
+        let words = name.trim_start_matches('_');
+        let mut out = name[..name.len() - words.len()].to_owned();
+
+        for (i, word) in words.split('_').filter(|word| !word.is_empty()).enumerate() {
+            let mut chars = word.chars();
+
+            if i == 0 && self == Self::Camel {
+                out.push_str(word);
+            } else if let Some(first) = chars.next() {
+                out.extend(first.to_uppercase());
+                out.push_str(chars.as_str());
+            }
+        }
+
+        // e.g. `type_` becomes the keyword `type`
+        syn::parse_str::<Ident>(&out).map_or_else(
+            |_| Ident::new_raw(&out, ident.span()),
+            |_| Ident::new(&out, ident.span()),
+        )
+    }
+}
+
+/// Convert a `CamelCase` type name to `snake_case`, leaving lowercase names as they are.
+///
+/// Runs of capitals are kept together as acronyms, so `HTTPConfig` becomes `http_config`.
+pub fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut out = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1);
+
+            // a capital starts a word after a lowercase letter or digit,
+            // or ends an acronym when followed by a lowercase letter
+            if prev.is_some_and(|prev| {
+                prev.is_lowercase()
+                    || prev.is_ascii_digit()
+                    || prev.is_uppercase() && next.is_some_and(|next| next.is_lowercase())
+            }) {
+                out.push('_');
+            }
+
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+
+    out
+}
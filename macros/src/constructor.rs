@@ -0,0 +1,108 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_quote, punctuated::Punctuated, Attribute, Ident, Member, Meta, Token, TypeGenerics,
+    Visibility,
+};
+
+use crate::{args::Args, errors::Errors, field::Field, types::generic_args};
+
+/// Generate the constructor requested by `#[ters(constructor)]`, taking every field
+/// as a parameter except the `Option` fields skipped by `#[ters(skip_none)]`.
+pub fn expand(
+    ident: &Ident,
+    args: &Args,
+    fields: &[Field],
+    errors: &mut Errors,
+) -> Option<TokenStream> {
+    if let (None, Some(span)) = (args.constructor, args.skip_none) {
+        errors.push(syn::Error::new(
+            span,
+            "`skip_none` only applies to the constructor; add `constructor` as well",
+        ));
+    }
+
+    let mut params = Vec::new();
+    let mut inits = Vec::new();
+
+    for field in fields {
+        let skipped = args.skip_none.is_some() && generic_args(&field.ty, "Option").is_some();
+
+        if let Some(attr) = &field.include_in_constructor {
+            if !skipped {
+                errors.push(syn::Error::new_spanned(
+                    attr,
+                    "`#[include_in_constructor]` only applies to `Option` fields skipped by `#[ters(skip_none)]`",
+                ));
+            }
+        }
+
+        let Field {
+            ident, member, ty, ..
+        } = field;
+        let cfgs = field.cfg_attrs();
+
+        if skipped && field.include_in_constructor.is_none() {
+            inits.push(quote! { #(#cfgs)* #member: ::core::option::Option::None });
+        } else {
+            params.push(quote! { #(#cfgs)* #ident: #ty });
+            inits.push(match member {
+                Member::Named(_) => quote! { #(#cfgs)* #ident },
+                Member::Unnamed(_) => quote! { #(#cfgs)* #member: #ident },
+            });
+        }
+    }
+
+    args.constructor?;
+
+    let doc = format!("Create a new `{ident}` from its fields.");
+    let allow_deprecated = fields
+        .iter()
+        .any(|field| field.attrs.iter().any(is_deprecated))
+        .then(|| quote! { #[allow(deprecated)] });
+    let vis: Visibility = args.default_vis().unwrap_or(parse_quote!(pub));
+
+    Some(quote! {
+        #[doc = #doc]
+        #allow_deprecated
+        #[inline]
+        #vis fn new(#(#params),*) -> Self {
+            Self {
+                #(#inits),*
+            }
+        }
+    })
+}
+
+/// Generate the `builder` entry point requested by `#[ters(generate_builder_fn)]`.
+///
+/// The `{Struct}Builder` type is expected to exist, with the same generics as the struct.
+pub fn builder_fn(ident: &Ident, ty_generics: &TypeGenerics, args: &Args) -> Option<TokenStream> {
+    args.generate_builder_fn?;
+
+    let builder = format_ident!("{ident}Builder");
+    let doc = format!("Create a [`{builder}`] for building a `{ident}`.");
+    let vis: Visibility = args.default_vis().unwrap_or(parse_quote!(pub));
+
+    Some(quote! {
+        #[doc = #doc]
+        #[inline]
+        #vis fn builder() -> #builder #ty_generics {
+            <#builder #ty_generics as ::core::default::Default>::default()
+        }
+    })
+}
+
+/// Whether a forwarded attribute deprecates the field, possibly conditionally.
+fn is_deprecated(attr: &Attribute) -> bool {
+    attr.path().is_ident("deprecated")
+        || attr.path().is_ident("cfg_attr")
+            && attr
+                .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .is_ok_and(|metas| {
+                    metas
+                        .iter()
+                        .skip(1)
+                        .any(|meta| meta.path().is_ident("deprecated"))
+                })
+}
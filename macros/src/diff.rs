@@ -0,0 +1,123 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_quote, spanned::Spanned, ItemStruct, Visibility};
+
+use crate::{
+    args::Args,
+    field::Field,
+    item::{non_exhaustive, require_named_fields},
+};
+
+/// Generate the `{Struct}Diff` struct requested by `#[ters(generate_diff)]`, holding an
+/// `Option` for each field which has a getter, along with the `diff` method filling them in.
+///
+/// With `#[ters(generate_merge)]`, the `merge` method applying a diff is generated too.
+///
+/// Returns the methods and the struct separately, as the methods belong in the impl block.
+pub fn expand(
+    item: &ItemStruct,
+    fields: &[Field],
+    args: &Args,
+    span: Span,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    require_named_fields(item, "generate_diff", span, false)?;
+
+    let ItemStruct { vis, ident, .. } = item;
+    let diff_ident = format_ident!("{ident}Diff");
+    let doc = format!(
+        "The fields of [`{ident}`] which have getters that differ, from [`{ident}::diff`]."
+    );
+    let method_doc = format!("Compare the fields which have getters, cloning those of `other` which differ into a [`{diff_ident}`].");
+    let method_vis: Visibility = args.default_vis().unwrap_or(parse_quote!(pub));
+
+    let fields = fields
+        .iter()
+        .filter(|field| !field.getters.is_empty())
+        .collect::<Vec<_>>();
+
+    let cfgs = fields
+        .iter()
+        .map(|field| field.cfg_attrs())
+        .collect::<Vec<_>>();
+    let docs = fields.iter().map(|field| {
+        field.docs_or(format!(
+            "The value of `{}` in `other`, if it differs.",
+            field.name()
+        ))
+    });
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let tys = fields.iter().map(|field| &field.ty);
+    let non_exhaustive = non_exhaustive(item);
+
+    let changes = fields.iter().map(|field| {
+        let Field { ident, ty, .. } = field;
+
+        quote_spanned! {ty.span()=>
+            if ::core::cmp::PartialEq::ne(&self.#ident, &other.#ident) {
+                ::core::option::Option::Some(::core::clone::Clone::clone(&other.#ident))
+            } else {
+                ::core::option::Option::None
+            }
+        }
+    });
+
+    let method = quote! {
+        #[doc = #method_doc]
+        #[inline]
+        #method_vis fn diff(&self, other: &Self) -> #diff_ident {
+            #diff_ident {
+                #(
+                    #(#cfgs)*
+                    #idents: #changes,
+                )*
+            }
+        }
+    };
+
+    // setters taking the value may normalize, check, or log it, and fields without one
+    // (such as read-only ones) are not settable, so are left out
+    let merge = args.generate_merge.map(|_| {
+        let doc = format!("Apply a [`{diff_ident}`], storing the fields which are `Some` through their setters.\n\nFields without a setter taking the value are left unchanged.");
+        let (stores, (cfgs, idents)): (Vec<_>, (Vec<_>, Vec<_>)) = fields
+            .iter()
+            .zip(&cfgs)
+            .filter_map(|(field, cfgs)| {
+                let (_, setter) = field
+                    .setters
+                    .iter()
+                    .find(|(_, setter)| setter.takes_value())?;
+                let method = setter.ident(&field.ident);
+
+                Some((quote! { self.#method(value); }, (cfgs, &field.ident)))
+            })
+            .unzip();
+
+        quote! {
+            #[doc = #doc]
+            #[inline]
+            #method_vis fn merge(&mut self, patch: #diff_ident) {
+                #(
+                    #(#cfgs)*
+                    if let ::core::option::Option::Some(value) = patch.#idents {
+                        #stores
+                    }
+                )*
+            }
+        }
+    });
+
+    let diff = quote! {
+        #[doc = #doc]
+        #[derive(::core::fmt::Debug)]
+        #non_exhaustive
+        #vis struct #diff_ident {
+            #(
+                #(#cfgs)*
+                #(#docs)*
+                #vis #idents: ::core::option::Option<#tys>,
+            )*
+        }
+    };
+
+    Ok((quote! { #method #merge }, diff))
+}
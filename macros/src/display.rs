@@ -0,0 +1,83 @@
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{ext::IdentExt, spanned::Spanned, Fields, ItemStruct};
+
+use crate::field::Field;
+
+/// Generate the `Display` impl requested by `#[ters(impl_display)]`, formatting the
+/// fields which have getters like `Debug` does, but with their `Display` representations.
+pub fn expand(item: &ItemStruct, fields: &[Field]) -> TokenStream {
+    let ident = &item.ident;
+    let name = ident.unraw().to_string();
+    let tuple = matches!(item.fields, Fields::Unnamed(_));
+
+    let fields = fields
+        .iter()
+        .filter(|field| !field.getters.is_empty())
+        .collect::<Vec<_>>();
+
+    let mut generics = item.generics.clone();
+    let where_clause = generics.make_where_clause();
+
+    // unmet bounds are reported against the field's type
+    for field in &fields {
+        let ty = &field.ty;
+
+        where_clause
+            .predicates
+            .push(syn::parse_quote_spanned! {ty.span()=> #ty: ::core::fmt::Display });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let entries = fields.iter().map(|field| {
+        let member = &field.member;
+        let cfgs = field.cfg_attrs();
+
+        if tuple {
+            quote_spanned! {member.span()=>
+                #(#cfgs)*
+                ::core::fmt::DebugTuple::field(&mut out, &AsDisplay(&self.#member));
+            }
+        } else {
+            let field_name = field.ident.unraw().to_string();
+
+            quote_spanned! {member.span()=>
+                #(#cfgs)*
+                ::core::fmt::DebugStruct::field(&mut out, #field_name, &AsDisplay(&self.#member));
+            }
+        }
+    });
+
+    let (start, finish) = if tuple {
+        (
+            quote! { ::core::fmt::Formatter::debug_tuple(f, #name) },
+            quote! { ::core::fmt::DebugTuple::finish(&mut out) },
+        )
+    } else {
+        (
+            quote! { ::core::fmt::Formatter::debug_struct(f, #name) },
+            quote! { ::core::fmt::DebugStruct::finish(&mut out) },
+        )
+    };
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                // `debug_struct` and `debug_tuple` handle the layout, including `{:#}`
+                struct AsDisplay<'a, T: ?::core::marker::Sized>(&'a T);
+
+                impl<T: ?::core::marker::Sized + ::core::fmt::Display> ::core::fmt::Debug for AsDisplay<'_, T> {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        ::core::fmt::Display::fmt(self.0, f)
+                    }
+                }
+
+                let mut out = #start;
+                #(#entries)*
+                #finish
+            }
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::{fmt::Write, fs, path::Path};
+
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Ident, ImplItem, ImplItemFn, ItemImpl};
+
+/// The environment variable naming the directory method listings are written to.
+pub const VAR: &str = "TERS_DUMP_METHODS";
+
+/// Write the listing of the methods generated for `ident` to `{dir}/{ident}.json`,
+/// or `{dir}/{ident}.{derive}.json` for a derive macro so each derive keeps its own.
+pub fn write<'a>(
+    dir: &Path,
+    ident: &Ident,
+    derive: Option<&str>,
+    names: impl IntoIterator<Item = &'a Ident>,
+    impl_: Option<&TokenStream>,
+) -> syn::Result<()> {
+    let path = match derive {
+        Some(derive) => dir.join(format!("{ident}.{derive}.json")),
+        None => dir.join(format!("{ident}.json")),
+    };
+
+    fs::create_dir_all(dir)
+        .and_then(|_| fs::write(&path, render(ident, names, impl_)))
+        .map_err(|e| {
+            syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "failed to write `{VAR}` listing to `{}`: {e}",
+                    path.display()
+                ),
+            )
+        })
+}
+
+/// Render the names and signatures of the generated methods as JSON, in the order of
+/// `names`, with the signatures taken from the generated impl block.
+pub fn render<'a>(
+    ident: &Ident,
+    names: impl IntoIterator<Item = &'a Ident>,
+    impl_: Option<&TokenStream>,
+) -> String {
+    let fns = impl_
+        .and_then(|impl_| syn::parse2::<ItemImpl>(impl_.clone()).ok())
+        .map(|impl_| impl_.items)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(method) => Some(method),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // names without a method, such as those of function pointer constants, are left out
+    let methods = names
+        .into_iter()
+        .filter_map(|name| fns.iter().find(|method| method.sig.ident == *name))
+        .map(|method| {
+            let ImplItemFn { vis, sig, .. } = method;
+
+            format!(
+                r#"{{"name":{},"signature":{}}}"#,
+                string(&sig.ident.to_string()),
+                string(&quote!(#vis #sig).to_string())
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        r#"{{"struct":{},"methods":[{}]}}"#,
+        string(&ident.to_string()),
+        methods.join(",")
+    )
+}
+
+/// Quote and escape `s` as a JSON string.
+fn string(s: &str) -> String {
+    let mut out = String::from('"');
+
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str(r#"\""#),
+            '\\' => out.push_str(r"\\"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+
+    out.push('"');
+    out
+}
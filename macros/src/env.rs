@@ -0,0 +1,130 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{ext::IdentExt, parse_quote, spanned::Spanned, ItemStruct, Member, Visibility};
+
+use crate::{args::Args, bounds::assert_bound, case::snake_case, field::Field};
+
+/// Generate the `set_from_env` method requested by `#[ters(generate_setters_from_env)]`,
+/// parsing each field with a setter from an environment variable named after it, along
+/// with the `{Struct}EnvError` it returns.
+///
+/// Returns the method and the error type separately, as the method belongs in the impl block.
+pub fn expand(
+    item: &ItemStruct,
+    fields: &[Field],
+    args: &Args,
+    span: Span,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    if !cfg!(feature = "std") {
+        return Err(syn::Error::new(
+            span,
+            "`generate_setters_from_env` requires the `std` feature of `ters`",
+        ));
+    }
+
+    // the values are stored through setters which take them whole
+    let setters = fields
+        .iter()
+        .filter_map(|field| {
+            let (_, setter) = field
+                .setters
+                .iter()
+                .find(|(_, setter)| setter.takes_value())?;
+
+            Some((field, setter.ident(&field.ident)))
+        })
+        .collect::<Vec<_>>();
+
+    if setters.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            "`generate_setters_from_env` requires at least one field with a setter",
+        ));
+    }
+
+    let ItemStruct { vis, ident, .. } = item;
+    let error = format_ident!("{ident}EnvError");
+    let prefix = snake_case(&ident.unraw().to_string()).to_uppercase();
+    let method_vis: Visibility = args.default_vis().unwrap_or(parse_quote!(pub));
+
+    let vars = setters
+        .iter()
+        .map(|(field, _)| {
+            let name = match &field.member {
+                Member::Named(_) => field.ident.unraw().to_string(),
+                Member::Unnamed(index) => index.index.to_string(),
+            };
+
+            format!("{prefix}_{}", name.to_uppercase())
+        })
+        .collect::<Vec<_>>();
+    let listed = vars
+        .iter()
+        .map(|var| format!("`{var}`"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let doc = format!("Parse the fields which have setters from the environment variables named after them, storing them through their setters: {listed}.\n\nFields whose variables are not set are left unchanged.");
+    let error_doc = format!("The error returned by [`{ident}::set_from_env`], naming the variable which could not be used.");
+
+    let reads = setters.iter().zip(&vars).map(|((field, method), var)| {
+        let ty = &field.ty;
+        let span = ty.span();
+        let bound = assert_bound(ty, quote_spanned! {span=> ::core::str::FromStr }, span);
+        let cfgs = field.cfg_attrs();
+
+        quote! {
+            #(#cfgs)*
+            {
+                #bound
+                match ::std::env::var(#var) {
+                    ::core::result::Result::Ok(value) => match <#ty as ::core::str::FromStr>::from_str(&value) {
+                        ::core::result::Result::Ok(parsed) => {
+                            self.#method(parsed);
+                        }
+                        ::core::result::Result::Err(_) => {
+                            return ::core::result::Result::Err(#error::Parse(#var, value));
+                        }
+                    },
+                    ::core::result::Result::Err(::std::env::VarError::NotPresent) => {}
+                    ::core::result::Result::Err(e) => {
+                        return ::core::result::Result::Err(#error::Var(#var, e));
+                    }
+                }
+            }
+        }
+    });
+
+    let method = quote! {
+        #[doc = #doc]
+        #method_vis fn set_from_env(&mut self) -> ::core::result::Result<(), #error> {
+            #(#reads)*
+            ::core::result::Result::Ok(())
+        }
+    };
+
+    let error = quote! {
+        #[doc = #error_doc]
+        #[derive(::core::fmt::Debug)]
+        #vis enum #error {
+            /// The variable is set, but not to valid unicode.
+            Var(&'static str, ::std::env::VarError),
+            /// The variable's value, which could not be parsed as the field's type.
+            Parse(&'static str, ::std::string::String),
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for #error {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    Self::Var(var, e) => ::core::write!(f, "`{}`: {}", var, e),
+                    Self::Parse(var, value) => ::core::write!(f, "`{}` could not be parsed from `{}`", var, value),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::std::error::Error for #error {}
+    };
+
+    Ok((method, error))
+}
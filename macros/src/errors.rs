@@ -0,0 +1,35 @@
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+
+/// Accumulates errors so every mistake is reported in a single compile.
+#[derive(Default)]
+pub struct Errors(Option<syn::Error>);
+
+impl Errors {
+    /// Record an error.
+    pub fn push(&mut self, e: syn::Error) {
+        match &mut self.0 {
+            Some(errors) => errors.combine(e),
+            None => self.0 = Some(e),
+        }
+    }
+
+    /// Record the error of `result`, if any, returning the success value otherwise.
+    pub fn ok<T>(&mut self, result: syn::Result<T>) -> Option<T> {
+        result.map_err(|e| self.push(e)).ok()
+    }
+
+    /// Return every recorded error combined into one.
+    #[cfg(test)]
+    pub fn finish(self) -> syn::Result<()> {
+        self.0.map_or(Ok(()), Err)
+    }
+}
+
+impl ToTokens for Errors {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some(errors) = &self.0 {
+            tokens.extend(errors.to_compile_error());
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{ext::IdentExt, Ident, ItemStruct, Visibility};
+
+use crate::{bounds::assert_bound, field::Field};
+
+/// The name of a function pointer constant for `field`, e.g. `FOO_SETTER`.
+pub fn const_ident(field: &Ident, suffix: &str) -> Ident {
+    format_ident!(
+        "{}_{suffix}",
+        field.unraw().to_string().to_uppercase(),
+        span = field.span()
+    )
+}
+
+/// Generate the constant requested by `#[get(fn_ptr)]`, holding an `extern "C"` function
+/// which returns a copy of the field of the struct pointed to.
+pub fn getter(
+    item: &ItemStruct,
+    field: &Field,
+    ident: &Ident,
+    vis: &Visibility,
+    span: Span,
+) -> syn::Result<TokenStream> {
+    check_generics(item, span)?;
+
+    let struct_ident = &item.ident;
+    let Field { member, ty, .. } = field;
+    let bound = assert_bound(ty, quote_spanned! {span=> ::core::marker::Copy }, span);
+    let safety =
+        format!("\n# Safety\n\nThe pointer must be valid for reads of a `{struct_ident}`.");
+
+    Ok(quote! {
+        #[doc = #safety]
+        #vis const #ident: unsafe extern "C" fn(*const #struct_ident) -> #ty = {
+            unsafe extern "C" fn getter(ptr: *const #struct_ident) -> #ty {
+                #bound
+                unsafe { (*ptr).#member }
+            }
+
+            getter
+        };
+    })
+}
+
+/// Generate the constant requested by `#[set(fn_ptr)]`, holding an `extern "C"` function
+/// which stores its argument into the field of the struct pointed to.
+pub fn setter(
+    item: &ItemStruct,
+    field: &Field,
+    ident: &Ident,
+    vis: &Visibility,
+    span: Span,
+) -> syn::Result<TokenStream> {
+    check_generics(item, span)?;
+
+    let struct_ident = &item.ident;
+    let Field { member, ty, .. } = field;
+    let bound = assert_bound(ty, quote_spanned! {span=> ::core::marker::Copy }, span);
+    let safety =
+        format!("\n# Safety\n\nThe pointer must be valid for writes of a `{struct_ident}`.");
+
+    Ok(quote! {
+        #[doc = #safety]
+        #vis const #ident: unsafe extern "C" fn(*mut #struct_ident, #ty) = {
+            unsafe extern "C" fn setter(ptr: *mut #struct_ident, value: #ty) {
+                #bound
+                unsafe {
+                    (*ptr).#member = value;
+                }
+            }
+
+            setter
+        };
+    })
+}
+
+/// The functions are nested items, which cannot name the impl's `Self` or generics.
+fn check_generics(item: &ItemStruct, span: Span) -> syn::Result<()> {
+    if item.generics.params.is_empty() {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            span,
+            "`fn_ptr` is not supported on generic structs",
+        ))
+    }
+}
@@ -0,0 +1,353 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse::ParseStream, parse_quote, punctuated::Punctuated, Attribute, Ident, Index, LitStr,
+    Member, Meta, Token, Type,
+};
+
+use crate::{args::Args, errors::Errors, getter::Getter, setter::Setter};
+
+/// A struct field along with the accessors requested for it.
+pub struct Field {
+    /// The name accessors are named after, `_0` and so on for tuple struct fields.
+    pub ident: Ident,
+    /// The field as accessed through `self`.
+    pub member: Member,
+    pub ty: Type,
+    /// Parsed `#[get]` attributes, alongside the attributes themselves.
+    pub getters: Vec<(Attribute, Getter)>,
+    /// Parsed `#[set]` attributes, alongside the attributes themselves.
+    pub setters: Vec<(Attribute, Setter)>,
+    /// Attributes copied onto every accessor.
+    pub attrs: Vec<Attribute>,
+    /// Documentation copied onto every accessor.
+    pub docs: Vec<Attribute>,
+    /// Search aliases from `#[doc(alias)]`, prefixed for each accessor.
+    pub aliases: Vec<LitStr>,
+    /// The `#[include_in_constructor]` attribute, if present.
+    pub include_in_constructor: Option<Attribute>,
+    /// Whether the accessors' documentation starts with a `Getter for` or `Setter for` heading.
+    headings: bool,
+}
+
+impl Field {
+    /// Strip the accessor attributes from `field`, collecting them along with the
+    /// attributes to forward onto the generated accessors.
+    pub fn parse(field: &mut syn::Field, index: usize, args: &Args, errors: &mut Errors) -> Self {
+        let mut getters = Vec::new();
+        let mut setters = Vec::new();
+        let mut include_in_constructor = None;
+
+        field.attrs.retain(|attr| {
+            if attr.path().is_ident("get") {
+                if let Some(getter) = errors.ok(Getter::parse(attr, args)) {
+                    let mutable_alias = getter.mutable_alias();
+
+                    getters.push((attr.clone(), getter));
+                    getters.extend(mutable_alias.map(|getter| (attr.clone(), getter)));
+                }
+                false
+            } else if attr.path().is_ident("set") {
+                if let Some(setter) = errors.ok(Setter::parse(attr, args)) {
+                    setters.push((attr.clone(), setter));
+                }
+                false
+            } else if attr.path().is_ident("include_in_constructor") {
+                if let Err(e) = attr.meta.require_path_only() {
+                    errors.push(e);
+                } else if include_in_constructor.is_some() {
+                    errors.push(syn::Error::new_spanned(
+                        attr,
+                        "duplicate `#[include_in_constructor]` attribute",
+                    ));
+                } else {
+                    include_in_constructor = Some(attr.clone());
+                }
+                false
+            } else {
+                true
+            }
+        });
+
+        // pinned fields are accessed through `pin_project`'s projections instead
+        if field.attrs.iter().any(|attr| attr.path().is_ident("pin")) {
+            for attr in getters
+                .iter()
+                .map(|(attr, _)| attr)
+                .chain(setters.iter().map(|(attr, _)| attr))
+            {
+                errors.push(syn::Error::new_spanned(
+                    attr,
+                    "accessors are not generated for `#[pin]` fields; use the projection provided by `pin_project` instead",
+                ));
+            }
+
+            getters.clear();
+            setters.clear();
+        }
+
+        // tuple struct fields have no name to derive method names from
+        if field.ident.is_none() {
+            let unnamed = getters
+                .iter()
+                .filter(|(_, getter)| !getter.has_name())
+                .map(|(attr, _)| attr)
+                .chain(
+                    setters
+                        .iter()
+                        .filter(|(_, setter)| !setter.has_name())
+                        .map(|(attr, _)| attr),
+                );
+
+            for attr in unnamed {
+                errors.push(syn::Error::new_spanned(
+                    attr,
+                    "accessors of tuple struct fields require a `name`",
+                ));
+            }
+
+            getters.retain(|(_, getter)| getter.has_name());
+            setters.retain(|(_, setter)| setter.has_name());
+        }
+
+        if let Some((read_only, _)) = getters.iter().find(|(_, getter)| getter.read_only) {
+            for (attr, _) in &setters {
+                errors.push(read_only_error(attr, read_only));
+            }
+
+            setters.clear();
+        }
+
+        let attrs = field
+            .attrs
+            .iter()
+            .flat_map(|attr| {
+                if attr.path().is_ident("cfg") {
+                    vec![attr.clone()]
+                } else if attr.path().is_ident("cfg_attr") {
+                    forwarded_cfg_attr(attr).into_iter().collect()
+                } else if attr.path().is_ident("deprecated") {
+                    // the accessor itself uses the deprecated field
+                    vec![attr.clone(), parse_quote!(#[allow(deprecated)])]
+                } else if args.propagate_lints && attr.path().is_ident("expect") {
+                    // an accessor need not trigger the lint the field expects,
+                    // which would make the expectation unfulfilled
+                    let mut attr = attr.clone();
+                    if let Meta::List(list) = &mut attr.meta {
+                        list.path = parse_quote!(allow);
+                    }
+                    vec![attr]
+                } else if args.propagate_lints && is_lint(attr) {
+                    vec![attr.clone()]
+                } else {
+                    vec![]
+                }
+            })
+            .collect();
+
+        let mut aliases = Vec::new();
+        let docs = field
+            .attrs
+            .iter()
+            .filter(|attr| {
+                attr.path()
+                    .get_ident()
+                    .map(|ident| ident == "doc")
+                    .is_some_and(|is_doc| is_doc)
+            })
+            .filter(|attr| match doc_aliases(attr) {
+                Some(found) => {
+                    aliases.extend(found);
+                    false
+                }
+                None => true,
+            })
+            .cloned()
+            .collect();
+
+        let (ident, member) = match &field.ident {
+            Some(ident) => (ident.clone(), Member::Named(ident.clone())),
+            None => (
+                format_ident!("_{index}"),
+                Member::Unnamed(Index::from(index)),
+            ),
+        };
+
+        Self {
+            ident,
+            member,
+            ty: field.ty.clone(),
+            getters,
+            setters,
+            attrs,
+            docs,
+            aliases,
+            include_in_constructor,
+            headings: !args.no_docs,
+        }
+    }
+
+    /// Attach the forwarded attributes and documentation to a generated method.
+    pub fn accessor(&self, kind: &str, method: TokenStream) -> TokenStream {
+        self.documented(
+            kind,
+            quote! {
+                #[inline]
+                #method
+            },
+        )
+    }
+
+    /// Wrap a generated associated constant in the attributes and documentation
+    /// forwarded from the field, like [`Field::accessor`] but without `#[inline]`.
+    pub fn constant(&self, kind: &str, constant: TokenStream) -> TokenStream {
+        self.documented(kind, constant)
+    }
+
+    /// The field's `#[cfg]` attributes, for generated code which must exist under the same conditions.
+    pub fn cfg_attrs(&self) -> Vec<&Attribute> {
+        self.attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .collect()
+    }
+
+    /// The field's documentation, or a `#[doc]` of `fallback` if it has none, for fields of
+    /// generated types which may be more visible than the field itself.
+    pub fn docs_or(&self, fallback: String) -> Vec<Attribute> {
+        if self.docs.is_empty() {
+            vec![parse_quote!(#[doc = #fallback])]
+        } else {
+            self.docs.clone()
+        }
+    }
+
+    /// The field as written in source, e.g. `foo` or `0`.
+    pub fn name(&self) -> String {
+        match &self.member {
+            Member::Named(ident) => ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        }
+    }
+
+    fn documented(&self, kind: &str, item: TokenStream) -> TokenStream {
+        let Self { attrs, docs, .. } = self;
+
+        let heading = self.headings.then(|| {
+            let heading = format!("{kind} for `");
+            let str_ident = self.name();
+
+            quote! {
+                #[doc = #heading]
+                #[doc = #str_ident]
+                #[doc = "`.\n\n"]
+            }
+        });
+
+        // e.g. `get_old_name` for a getter of a field aliased `old_name`
+        let prefix = match kind {
+            "Getter" => "get",
+            _ => "set",
+        };
+        let aliases = self
+            .aliases
+            .iter()
+            .map(|alias| LitStr::new(&format!("{prefix}_{}", alias.value()), alias.span()));
+
+        quote! {
+            #(#attrs)*
+            #heading
+            #(#docs)*
+            #(#[doc(alias = #aliases)])*
+            #item
+        }
+    }
+}
+
+/// The aliases declared by a `#[doc(alias = "...")]` or `#[doc(alias("...", ...))]` attribute.
+///
+/// Returns `None` if the attribute contains anything other than aliases.
+fn doc_aliases(attr: &Attribute) -> Option<Vec<LitStr>> {
+    let Meta::List(_) = attr.meta else {
+        return None;
+    };
+
+    let mut aliases = Vec::new();
+
+    attr.parse_nested_meta(|meta| {
+        if !meta.path.is_ident("alias") {
+            return Err(meta.error("not an alias"));
+        }
+
+        if meta.input.peek(Token![=]) {
+            aliases.push(meta.value()?.parse()?);
+        } else {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            aliases.extend(Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?);
+        }
+
+        Ok(())
+    })
+    .ok()?;
+
+    Some(aliases)
+}
+
+/// Whether an attribute sets a lint level.
+fn is_lint(attr: &Attribute) -> bool {
+    ["allow", "expect", "warn", "deny", "forbid"]
+        .iter()
+        .any(|level| attr.path().is_ident(level))
+}
+
+/// Reduce a field's `#[cfg_attr]` to the inner attributes which are
+/// meaningful on an accessor (`cfg`, `doc`, and `deprecated`).
+///
+/// A conditional deprecation is accompanied by a matching `allow(deprecated)`.
+///
+/// Returns `None` if no inner attributes remain.
+fn forwarded_cfg_attr(attr: &Attribute) -> Option<Attribute> {
+    let (predicate, attrs) = attr
+        .parse_args_with(|input: ParseStream| {
+            let predicate = input.parse::<Meta>()?;
+            input.parse::<Token![,]>()?;
+            let attrs = Punctuated::<Meta, Token![,]>::parse_terminated(input)?;
+
+            Ok((predicate, attrs))
+        })
+        .ok()?;
+
+    let mut attrs = attrs
+        .into_iter()
+        .filter(|meta| {
+            meta.path().is_ident("cfg")
+                || meta.path().is_ident("doc")
+                || meta.path().is_ident("deprecated")
+        })
+        .collect::<Vec<_>>();
+
+    if attrs.iter().any(|meta| meta.path().is_ident("deprecated")) {
+        attrs.push(parse_quote!(allow(deprecated)));
+    }
+
+    (!attrs.is_empty()).then(|| {
+        parse_quote! {
+            #[cfg_attr(#predicate, #(#attrs),*)]
+        }
+    })
+}
+
+/// The error for a `#[set]` on a field declared read-only by the `#[get]` given.
+pub fn read_only_error(setter: &Attribute, read_only: &Attribute) -> syn::Error {
+    let mut e = syn::Error::new_spanned(
+        setter,
+        "setters cannot be generated for a field declared `#[get(read_only)]`",
+    );
+    e.combine(syn::Error::new_spanned(
+        read_only,
+        "field declared read-only here",
+    ));
+
+    e
+}
@@ -0,0 +1,40 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Fields, Index, ItemStruct};
+
+/// Generate the `From` impls requested by `#[ters(impl_from_tuple)]`, converting a tuple
+/// struct to and from a tuple of its field types, in order.
+pub fn expand(item: &ItemStruct, span: Span) -> syn::Result<TokenStream> {
+    let Fields::Unnamed(fields) = &item.fields else {
+        return Err(syn::Error::new(
+            span,
+            "`impl_from_tuple` requires a tuple struct",
+        ));
+    };
+
+    let ident = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    let tys = fields.unnamed.iter().map(|field| &field.ty);
+    let tuple = quote! { (#(#tys,)*) };
+    let bindings = (0..fields.unnamed.len())
+        .map(|i| format_ident!("_{i}"))
+        .collect::<Vec<_>>();
+    let indices = (0..fields.unnamed.len()).map(Index::from);
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#tuple> for #ident #ty_generics #where_clause {
+            fn from((#(#bindings,)*): #tuple) -> Self {
+                Self(#(#bindings),*)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#ident #ty_generics> for #tuple #where_clause {
+            fn from(value: #ident #ty_generics) -> Self {
+                (#(value.#indices,)*)
+            }
+        }
+    })
+}
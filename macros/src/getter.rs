@@ -0,0 +1,728 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{
+    parse_quote, punctuated::Punctuated, spanned::Spanned, Attribute, Expr, Ident, Member, Meta,
+    Pat, Path, Token, Type, Visibility,
+};
+
+use crate::{
+    args::Args,
+    bounds::assert_bound,
+    case::{snake_case, Case},
+    ffi,
+    field::Field,
+    names::{parse_method_name, parse_vis},
+    types::{generic_arg, generic_args},
+};
+
+/// What a getter returns.
+#[derive(Clone, Default)]
+enum Flavor {
+    /// A reference to the field.
+    #[default]
+    Ref,
+    /// A mutable reference to the field.
+    Mut,
+    /// A copy of the field, requiring `Copy`.
+    Copy(Span),
+    /// A clone of the field, requiring `Clone`.
+    Clone(Span),
+    /// The field as `&dyn Any`, hiding the concrete type.
+    Opaque(Span),
+    /// The field formatted by the given function, as `impl Display`.
+    FormatWith(Path),
+    /// The field formatted with `{:?}`, as a `String`.
+    DebugStr(Span),
+    /// The name of the field, as a `&'static str`.
+    FieldNameStr,
+    /// References to the field and the next one, whose name and type are filled in
+    /// once all fields are known.
+    ZipAdjacent(Span, Option<Box<(Ident, Member, Type)>>),
+    /// Whether the field matches the given pattern.
+    Matches(Box<Pat>),
+    /// The success value of a `Result` field, as `Option<&T>`.
+    Ok,
+    /// The error value of a `Result` field, as `Option<&E>`.
+    Err,
+    /// Whether a `Result` field holds a success value.
+    IsOk,
+    /// The value of an `Option` field, as `Option<&T>`, debug-asserted to be `Some`.
+    DebugAssertSome,
+    /// The pointer to the data managed by an `Arc` field.
+    ArcPtr,
+    /// The discriminant of an enum field.
+    Discriminant,
+    /// The element popped off the front of a `VecDeque` field.
+    PopFront,
+    /// The element popped off the back of a `VecDeque` field.
+    PopBack,
+    /// The field's bits reinterpreted as the given type, unsafely.
+    TransmuteTo(Box<Type>),
+    /// The hash of the field, computed with the given hasher or `DefaultHasher`.
+    HashValue(Span, Option<Path>),
+    /// An `extern "C"` function pointer constant reading the field, instead of a method.
+    FnPtr(Span),
+    /// The given constant expression, from an associated `const fn` instead of a method.
+    ConstValue(Span, Box<Expr>),
+}
+
+impl Flavor {
+    /// The option selecting this flavor.
+    fn keyword(&self) -> &'static str {
+        match self {
+            Self::Ref => "ref",
+            Self::Mut => "mut",
+            Self::Copy(_) => "copy",
+            Self::Clone(_) => "clone",
+            Self::Opaque(_) => "opaque",
+            Self::FormatWith(_) => "format_with",
+            Self::DebugStr(_) => "debug_str",
+            Self::FieldNameStr => "field_name_str",
+            Self::ZipAdjacent(..) => "zip_adjacent",
+            Self::Matches(_) => "matches",
+            Self::Ok => "ok",
+            Self::Err => "err",
+            Self::IsOk => "is_ok",
+            Self::DebugAssertSome => "debug_assert_some",
+            Self::ArcPtr => "arc_ptr",
+            Self::Discriminant => "discriminant",
+            Self::PopFront => "pop_front",
+            Self::PopBack => "pop_back",
+            Self::TransmuteTo(_) => "transmute_to",
+            Self::HashValue(..) => "hash_value",
+            Self::FnPtr(_) => "fn_ptr",
+            Self::ConstValue(..) => "const_value",
+        }
+    }
+
+    /// Whether the generated code uses `std`.
+    fn requires_std(&self) -> bool {
+        matches!(
+            self,
+            Self::ArcPtr
+                | Self::DebugStr(_)
+                | Self::PopFront
+                | Self::PopBack
+                | Self::HashValue(_, None)
+        )
+    }
+}
+
+/// Options accepted by `#[get(...)]`.
+#[derive(Clone, Default)]
+pub struct Getter {
+    /// Overrides the generated method name.
+    name: Option<Ident>,
+    /// Overrides the visibility of the generated method, which is `pub` by default.
+    vis: Option<Visibility>,
+    /// What the getter returns.
+    flavor: Flavor,
+    /// Hook called whenever the getter is invoked.
+    ///
+    /// Closures receive the field name as a `&'static str`.
+    on_read: Option<Expr>,
+    /// Whether the getter is bounded by `where Self: Sized`.
+    sized: bool,
+    /// Whether the getter is an `async fn`.
+    async_fn: bool,
+    /// The option (`mutable_alias`, `with_mut`, or `mut_pair`) requesting a `_mut` getter
+    /// alongside this one.
+    with_mut: Option<Ident>,
+    /// Whether the field is declared read-only, forbidding setters.
+    pub read_only: bool,
+    /// The case the generated method name is converted to, unless given a `name`.
+    rename_all: Option<Case>,
+}
+
+impl Getter {
+    pub fn parse(attr: &Attribute, args: &Args) -> syn::Result<Self> {
+        let mut getter = Self::default();
+
+        if let Meta::List(_) = attr.meta {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    if getter.name.is_some() {
+                        return Err(meta.error("duplicate `name` option"));
+                    }
+
+                    getter.name = Some(parse_method_name(&meta.value()?.parse()?)?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("vis") {
+                    if getter.vis.is_some() {
+                        return Err(meta.error("duplicate `vis` option"));
+                    }
+
+                    getter.vis = Some(parse_vis(meta.value()?)?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("on_read") {
+                    if getter.on_read.is_some() {
+                        return Err(meta.error("duplicate `on_read` option"));
+                    }
+
+                    getter.on_read = Some(meta.value()?.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("sized") {
+                    if getter.sized {
+                        return Err(meta.error("duplicate `sized` option"));
+                    }
+
+                    getter.sized = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("read_only") {
+                    if getter.read_only {
+                        return Err(meta.error("duplicate `read_only` option"));
+                    }
+
+                    getter.read_only = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("async_fn") {
+                    if !cfg!(feature = "async") {
+                        return Err(meta.error("`async_fn` requires the `async` feature of `ters`"));
+                    }
+
+                    if getter.async_fn {
+                        return Err(meta.error("duplicate `async_fn` option"));
+                    }
+
+                    getter.async_fn = true;
+                    return Ok(());
+                }
+
+                if ["mutable_alias", "with_mut", "mut_pair"]
+                    .iter()
+                    .any(|option| meta.path.is_ident(option))
+                {
+                    if let Some(with_mut) = &getter.with_mut {
+                        return Err(meta.error(format!("duplicate `{with_mut}` option")));
+                    }
+
+                    getter.with_mut = meta.path.get_ident().cloned();
+                    return Ok(());
+                }
+
+                let span = meta.path.span();
+                let flavor = if meta.path.is_ident("mut") {
+                    Flavor::Mut
+                } else if meta.path.is_ident("copy") {
+                    Flavor::Copy(span)
+                } else if meta.path.is_ident("clone") {
+                    Flavor::Clone(span)
+                } else if meta.path.is_ident("opaque") {
+                    Flavor::Opaque(span)
+                } else if meta.path.is_ident("format_with") {
+                    Flavor::FormatWith(meta.value()?.parse()?)
+                } else if meta.path.is_ident("debug_str") {
+                    Flavor::DebugStr(span)
+                } else if meta.path.is_ident("field_name_str") {
+                    Flavor::FieldNameStr
+                } else if meta.path.is_ident("zip_adjacent") {
+                    Flavor::ZipAdjacent(span, None)
+                } else if meta.path.is_ident("matches") {
+                    Flavor::Matches(Box::new(Pat::parse_multi_with_leading_vert(meta.value()?)?))
+                } else if meta.path.is_ident("ok") {
+                    Flavor::Ok
+                } else if meta.path.is_ident("err") {
+                    Flavor::Err
+                } else if meta.path.is_ident("is_ok") {
+                    Flavor::IsOk
+                } else if meta.path.is_ident("debug_assert_some") {
+                    Flavor::DebugAssertSome
+                } else if meta.path.is_ident("arc_ptr") {
+                    Flavor::ArcPtr
+                } else if meta.path.is_ident("discriminant") {
+                    Flavor::Discriminant
+                } else if meta.path.is_ident("pop_front") {
+                    Flavor::PopFront
+                } else if meta.path.is_ident("pop_back") {
+                    Flavor::PopBack
+                } else if meta.path.is_ident("transmute_to") {
+                    Flavor::TransmuteTo(meta.value()?.parse()?)
+                } else if meta.path.is_ident("fn_ptr") {
+                    if !cfg!(feature = "ffi") {
+                        return Err(meta.error("`fn_ptr` requires the `ffi` feature of `ters`"));
+                    }
+
+                    Flavor::FnPtr(span)
+                } else if meta.path.is_ident("const_value") {
+                    Flavor::ConstValue(span, meta.value()?.parse()?)
+                } else if meta.path.is_ident("hash_value") {
+                    let hasher = if meta.input.peek(Token![=]) {
+                        Some(meta.value()?.parse()?)
+                    } else {
+                        None
+                    };
+
+                    Flavor::HashValue(span, hasher)
+                } else {
+                    return Err(meta.error("unrecognized getter option"));
+                };
+
+                if flavor.requires_std() && !cfg!(feature = "std") {
+                    return Err(meta.error(format!(
+                        "`{}` requires the `std` feature of `ters`",
+                        flavor.keyword()
+                    )));
+                }
+
+                if !matches!(getter.flavor, Flavor::Ref) {
+                    return Err(meta.error(format!(
+                        "`{}` cannot be combined with `{}`",
+                        flavor.keyword(),
+                        getter.flavor.keyword()
+                    )));
+                }
+
+                getter.flavor = flavor;
+                Ok(())
+            })?;
+        }
+
+        if getter.vis.is_none() {
+            getter.vis = args.default_vis();
+        }
+
+        getter.rename_all = args.rename_all;
+
+        // the function only reads the field, and is not a method to hook into
+        if let Flavor::FnPtr(span) = getter.flavor {
+            let options = [
+                ("on_read", getter.on_read.is_some()),
+                ("sized", getter.sized),
+                ("async_fn", getter.async_fn),
+            ];
+
+            if let Some((option, _)) = options.iter().find(|(_, set)| *set) {
+                return Err(syn::Error::new(
+                    span,
+                    format!("`fn_ptr` cannot be combined with `{option}`"),
+                ));
+            }
+        }
+
+        // the value is known at compile time, without reading anything
+        if let Flavor::ConstValue(span, _) = getter.flavor {
+            let options = [
+                ("on_read", getter.on_read.is_some()),
+                ("async_fn", getter.async_fn),
+            ];
+
+            if let Some((option, _)) = options.iter().find(|(_, set)| *set) {
+                return Err(syn::Error::new(
+                    span,
+                    format!("`const_value` cannot be combined with `{option}`"),
+                ));
+            }
+        }
+
+        if let Some(with_mut) = &getter.with_mut {
+            if !matches!(getter.flavor, Flavor::Ref) {
+                return Err(syn::Error::new_spanned(
+                    with_mut,
+                    format!(
+                        "`{with_mut}` cannot be combined with `{}`",
+                        getter.flavor.keyword()
+                    ),
+                ));
+            }
+        }
+
+        if getter.read_only && (getter.with_mut.is_some() || matches!(getter.flavor, Flavor::Mut)) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "a field declared `read_only` cannot have a mutable getter",
+            ));
+        }
+
+        if matches!(getter.flavor, Flavor::Matches(_)) && getter.name.is_none() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "`matches` requires a `name` for the generated predicate",
+            ));
+        }
+
+        if let Flavor::TransmuteTo(target) = &getter.flavor {
+            if getter.name.is_none() && target_ident(target).is_none() {
+                return Err(syn::Error::new_spanned(
+                    target,
+                    "`transmute_to` requires a `name` unless the target is a plain type name",
+                ));
+            }
+        }
+
+        Ok(getter)
+    }
+
+    /// The `_mut` getter requested alongside this one, sharing its other options.
+    pub fn mutable_alias(&self) -> Option<Self> {
+        self.with_mut.as_ref()?;
+
+        Some(Self {
+            name: self.name.as_ref().map(|name| format_ident!("{name}_mut")),
+            flavor: Flavor::Mut,
+            with_mut: None,
+            ..self.clone()
+        })
+    }
+
+    /// Provide the name and type of the field following this getter's, if any.
+    ///
+    /// Fails if this getter zips its field with the next one but there is none.
+    pub fn zip_with(&mut self, next: Option<&(Ident, Member, Type)>) -> syn::Result<()> {
+        if let Flavor::ZipAdjacent(span, adjacent) = &mut self.flavor {
+            *adjacent = Some(next.cloned().map(Box::new).ok_or_else(|| {
+                syn::Error::new(
+                    *span,
+                    "`zip_adjacent` cannot be applied to the last field of the struct",
+                )
+            })?);
+        }
+
+        Ok(())
+    }
+
+    /// The span of the `fn_ptr` option, if a function pointer constant is requested instead.
+    pub fn fn_ptr(&self) -> Option<Span> {
+        match self.flavor {
+            Flavor::FnPtr(span) => Some(span),
+            _ => None,
+        }
+    }
+
+    /// Whether the method is named explicitly rather than after the field.
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    /// The visibility of the generated method.
+    pub fn vis(&self) -> Visibility {
+        self.vis.clone().unwrap_or(parse_quote!(pub))
+    }
+
+    /// The name of the generated method.
+    pub fn ident(&self, field: &Ident) -> Ident {
+        let ident = match (&self.name, &self.flavor) {
+            (Some(name), _) => return name.clone(),
+            (None, Flavor::Mut) => format_ident!("{field}_mut"),
+            (None, Flavor::FormatWith(_)) => format_ident!("{field}_display"),
+            (None, Flavor::DebugStr(_)) => format_ident!("{field}_debug_str"),
+            (None, Flavor::FieldNameStr) => format_ident!("{field}_as_str"),
+            (None, Flavor::ZipAdjacent(_, adjacent)) => {
+                // filled in before any names are needed
+                let (next, ..) = adjacent.as_deref().unwrap();
+
+                format_ident!("{field}{next}")
+            }
+            (None, Flavor::Ok) => format_ident!("{field}_ok"),
+            (None, Flavor::Err) => format_ident!("{field}_err"),
+            (None, Flavor::IsOk) => format_ident!("{field}_is_ok"),
+            (None, Flavor::ArcPtr) => format_ident!("{field}_ptr"),
+            (None, Flavor::Discriminant) => format_ident!("{field}_discriminant"),
+            (None, Flavor::HashValue(..)) => format_ident!("{field}_hash"),
+            (None, Flavor::FnPtr(_)) => return ffi::const_ident(field, "GETTER"),
+            (None, Flavor::TransmuteTo(target)) => {
+                // checked while parsing
+                let target = target_ident(target).unwrap();
+
+                format_ident!("{field}_as_{}", snake_case(&target.to_string()))
+            }
+            (None, flavor @ (Flavor::PopFront | Flavor::PopBack)) => {
+                format_ident!("{}_{field}", flavor.keyword())
+            }
+            (None, _) => field.clone(),
+        };
+
+        match self.rename_all {
+            Some(case) => case.apply(&ident),
+            None => ident,
+        }
+    }
+
+    /// Generate the getter method for `field`.
+    pub fn expand(&self, field: &Field) -> syn::Result<TokenStream> {
+        let Field {
+            ident, member, ty, ..
+        } = field;
+        let method_ident = self.ident(ident);
+        let str_ident = field.name();
+
+        let on_read = self.on_read.as_ref().map(|on_read| match on_read {
+            Expr::Closure(closure) if !closure.inputs.is_empty() => quote! {
+                (#closure)(#str_ident);
+            },
+            on_read => quote! {
+                (#on_read)();
+            },
+        });
+
+        let (output, assertion, body) = match &self.flavor {
+            Flavor::Ref => (quote! { &#ty }, None, quote! { &self.#member }),
+            Flavor::Mut => (quote! { &mut #ty }, None, quote! { &mut self.#member }),
+            Flavor::Copy(span) => (
+                quote! { #ty },
+                Some(assert_bound(
+                    ty,
+                    quote_spanned! {*span=> ::core::marker::Copy },
+                    *span,
+                )),
+                quote! { self.#member },
+            ),
+            Flavor::Clone(span) => (
+                quote! { #ty },
+                Some(assert_bound(
+                    ty,
+                    quote_spanned! {*span=> ::core::clone::Clone },
+                    *span,
+                )),
+                quote! { ::core::clone::Clone::clone(&self.#member) },
+            ),
+            Flavor::Opaque(span) => (
+                quote! { &dyn ::core::any::Any },
+                Some(assert_bound(
+                    ty,
+                    quote_spanned! {*span=> ::core::any::Any },
+                    *span,
+                )),
+                quote! { &self.#member },
+            ),
+            Flavor::FormatWith(format_with) => (
+                quote! { impl ::core::fmt::Display + '_ },
+                None,
+                quote! { #format_with(&self.#member) },
+            ),
+            Flavor::DebugStr(span) => (
+                quote! { ::std::string::String },
+                Some(assert_bound(
+                    ty,
+                    quote_spanned! {*span=> ::core::fmt::Debug },
+                    *span,
+                )),
+                quote! { ::std::format!("{:?}", self.#member) },
+            ),
+            Flavor::FieldNameStr => (
+                quote! { &'static str },
+                None,
+                quote! { ::core::stringify!(#member) },
+            ),
+            Flavor::ZipAdjacent(_, adjacent) => {
+                let (_, next, next_ty) = adjacent.as_deref().unwrap();
+
+                (
+                    quote! { (&#ty, &#next_ty) },
+                    None,
+                    quote! { (&self.#member, &self.#next) },
+                )
+            }
+            Flavor::Matches(pat) => (
+                quote! { bool },
+                None,
+                quote! { ::core::matches!(self.#member, #pat) },
+            ),
+            Flavor::Ok => {
+                let [ok, _] = result_args(ty, &self.flavor)?;
+
+                (
+                    quote! { ::core::option::Option<&#ok> },
+                    None,
+                    quote! { ::core::result::Result::ok(::core::result::Result::as_ref(&self.#member)) },
+                )
+            }
+            Flavor::Err => {
+                let [_, err] = result_args(ty, &self.flavor)?;
+
+                (
+                    quote! { ::core::option::Option<&#err> },
+                    None,
+                    quote! { ::core::result::Result::err(::core::result::Result::as_ref(&self.#member)) },
+                )
+            }
+            Flavor::IsOk => {
+                result_args(ty, &self.flavor)?;
+
+                (
+                    quote! { bool },
+                    None,
+                    quote! { ::core::result::Result::is_ok(&self.#member) },
+                )
+            }
+            Flavor::DebugAssertSome => {
+                let inner = generic_arg(ty, "Option").ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        ty,
+                        "`debug_assert_some` requires a field of type `Option<T>`",
+                    )
+                })?;
+                let message = format!("`{}` expected to be `Some`", field.name());
+
+                (
+                    quote! { ::core::option::Option<&#inner> },
+                    Some(quote! {
+                        ::core::debug_assert!(::core::option::Option::is_some(&self.#member), #message);
+                    }),
+                    quote! { ::core::option::Option::as_ref(&self.#member) },
+                )
+            }
+            Flavor::ArcPtr => {
+                let inner = generic_arg(ty, "Arc").ok_or_else(|| {
+                    syn::Error::new_spanned(ty, "`arc_ptr` requires a field of type `Arc<T>`")
+                })?;
+
+                (
+                    quote! { *const #inner },
+                    None,
+                    quote! { ::std::sync::Arc::as_ptr(&self.#member) },
+                )
+            }
+            Flavor::Discriminant => (
+                quote! { ::core::mem::Discriminant<#ty> },
+                None,
+                quote! { ::core::mem::discriminant(&self.#member) },
+            ),
+            Flavor::TransmuteTo(target) => {
+                let span = target.span();
+                let copy = assert_bound(ty, quote_spanned! {span=> ::core::marker::Copy }, span);
+
+                // `transmute_copy` does not check sizes itself, leaving the assertion
+                // below as the only (and clearer) error on a mismatch; an inline `const`
+                // rather than an item, since items cannot name the struct's generics
+                (
+                    quote! { #target },
+                    Some(quote_spanned! {span=>
+                        #copy
+                        const {
+                            ::core::assert!(
+                                ::core::mem::size_of::<#ty>() == ::core::mem::size_of::<#target>(),
+                                "`transmute_to` requires the field and target types to have the same size",
+                            );
+                        }
+                    }),
+                    quote! { unsafe { ::core::mem::transmute_copy::<#ty, #target>(&self.#member) } },
+                )
+            }
+            Flavor::ConstValue(_, value) => (quote! { #ty }, None, quote! { #value }),
+            Flavor::FnPtr(_) => {
+                unreachable!("function pointer constants are generated by `ffi::getter`")
+            }
+            Flavor::HashValue(span, hasher) => {
+                let hasher = match hasher {
+                    Some(hasher) => quote! { <#hasher as ::core::default::Default>::default() },
+                    None => quote! { ::std::collections::hash_map::DefaultHasher::new() },
+                };
+
+                (
+                    quote! { u64 },
+                    Some(assert_bound(
+                        ty,
+                        quote_spanned! {*span=> ::core::hash::Hash },
+                        *span,
+                    )),
+                    quote! {
+                        let mut hasher = #hasher;
+                        ::core::hash::Hash::hash(&self.#member, &mut hasher);
+                        ::core::hash::Hasher::finish(&hasher)
+                    },
+                )
+            }
+            Flavor::PopFront | Flavor::PopBack => {
+                let element = generic_arg(ty, "VecDeque").ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        ty,
+                        format!(
+                            "`{}` requires a field of type `VecDeque<T>`",
+                            self.flavor.keyword()
+                        ),
+                    )
+                })?;
+                let method = format_ident!("{}", self.flavor.keyword());
+
+                (
+                    quote! { ::core::option::Option<#element> },
+                    None,
+                    quote! { ::std::collections::VecDeque::#method(&mut self.#member) },
+                )
+            }
+        };
+
+        let (unsafety, safety) = match &self.flavor {
+            Flavor::TransmuteTo(target) => {
+                let doc = format!(
+                    "\n# Safety\n\nThe bits of `{}` must be a valid `{}`.",
+                    field.name(),
+                    target.to_token_stream()
+                );
+
+                (Some(quote! { unsafe }), Some(quote! { #[doc = #doc] }))
+            }
+            _ => (None, None),
+        };
+
+        // popping consumes from the field, so needs mutable access despite being a getter
+        // while constant values need no access at all
+        let receiver = match self.flavor {
+            Flavor::Mut | Flavor::PopFront | Flavor::PopBack => Some(quote! { &mut self }),
+            Flavor::ConstValue(..) => None,
+            _ => Some(quote! { &self }),
+        };
+        let constness = matches!(self.flavor, Flavor::ConstValue(..)).then(|| quote! { const });
+
+        let where_clause = self
+            .sized
+            .then(|| quote! { where Self: ::core::marker::Sized });
+
+        let vis = self.vis();
+        let asyncness = self.async_fn.then(|| quote! { async });
+
+        Ok(quote! {
+            #safety
+            #vis #constness #asyncness #unsafety fn #method_ident(#receiver) -> #output #where_clause {
+                #assertion
+                #on_read
+                #body
+            }
+        })
+    }
+}
+
+/// Whether `attr` is a `#[get]` declaring the field `read_only`, before it is parsed.
+pub fn is_read_only(attr: &Attribute) -> bool {
+    attr.path().is_ident("get")
+        && attr
+            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .is_ok_and(|metas| metas.iter().any(|meta| meta.path().is_ident("read_only")))
+}
+
+/// The success and error types of a field written as `Result<T, E>`.
+fn result_args<'a>(ty: &'a Type, flavor: &Flavor) -> syn::Result<[&'a Type; 2]> {
+    generic_args(ty, "Result")
+        .and_then(|args| args.try_into().ok())
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                ty,
+                format!(
+                    "`{}` requires a field of type `Result<T, E>`",
+                    flavor.keyword()
+                ),
+            )
+        })
+}
+
+/// The name of a transmute target written as a plain path, e.g. `f32` or `core::num::NonZeroU32`.
+fn target_ident(target: &Type) -> Option<&Ident> {
+    match target {
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+
+            segment.arguments.is_none().then_some(&segment.ident)
+        }
+        _ => None,
+    }
+}
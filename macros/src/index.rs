@@ -0,0 +1,93 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::ItemStruct;
+
+use crate::{
+    case::Case,
+    field::Field,
+    item::{non_exhaustive, require_named_fields},
+};
+
+/// Generate the `{Struct}Field` enum requested by `#[ters(generate_index)]`, naming the
+/// fields which have getters, along with the `Index` impl selecting them by variant.
+pub fn expand(item: &ItemStruct, fields: &[Field], span: Span) -> syn::Result<TokenStream> {
+    require_named_fields(item, "generate_index", span, true)?;
+
+    let fields = fields
+        .iter()
+        .filter(|field| !field.getters.is_empty())
+        .collect::<Vec<_>>();
+
+    // `Index` has a single `Output`
+    let Some(output) = fields.first().map(|field| &field.ty) else {
+        return Err(syn::Error::new(
+            span,
+            "`generate_index` requires at least one field with a getter",
+        ));
+    };
+
+    let output_str = output.to_token_stream().to_string();
+    let mut mismatched = fields
+        .iter()
+        .filter(|field| field.ty.to_token_stream().to_string() != output_str)
+        .map(|field| {
+            syn::Error::new_spanned(
+                &field.ty,
+                format!("`generate_index` requires the fields with getters to share a type, `{output_str}` here"),
+            )
+        });
+
+    if let Some(mut e) = mismatched.next() {
+        mismatched.for_each(|other| e.combine(other));
+        return Err(e);
+    }
+
+    let ItemStruct {
+        vis,
+        ident,
+        generics,
+        ..
+    } = item;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let enum_ident = format_ident!("{ident}Field");
+    let doc = format!("The fields of [`{ident}`] which have getters, for indexing it.");
+
+    let cfgs = fields
+        .iter()
+        .map(|field| field.cfg_attrs())
+        .collect::<Vec<_>>();
+    let docs = fields.iter().map(|field| &field.docs);
+    let variants = fields
+        .iter()
+        .map(|field| Case::Pascal.apply(&field.ident))
+        .collect::<Vec<_>>();
+    let members = fields.iter().map(|field| &field.member);
+    let non_exhaustive = non_exhaustive(item);
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::fmt::Debug, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+        #non_exhaustive
+        #vis enum #enum_ident {
+            #(
+                #(#cfgs)*
+                #(#docs)*
+                #variants,
+            )*
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::ops::Index<#enum_ident> for #ident #ty_generics #where_clause {
+            type Output = #output;
+
+            fn index(&self, field: #enum_ident) -> &Self::Output {
+                match field {
+                    #(
+                        #(#cfgs)*
+                        #enum_ident::#variants => &self.#members,
+                    )*
+                }
+            }
+        }
+    })
+}
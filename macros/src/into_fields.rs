@@ -0,0 +1,61 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::ItemStruct;
+
+use crate::{
+    field::Field,
+    item::{non_exhaustive, require_named_fields},
+};
+
+/// Generate the `{Struct}Fields` struct requested by `#[ters(into_fields)]`, holding the
+/// fields which have getters, along with the `From` impl moving them out of the struct.
+pub fn expand(item: &ItemStruct, fields: &[Field], span: Span) -> syn::Result<TokenStream> {
+    require_named_fields(item, "into_fields", span, false)?;
+
+    let ItemStruct { vis, ident, .. } = item;
+    let fields_ident = format_ident!("{ident}Fields");
+    let doc = format!("The fields of [`{ident}`] which have getters, moved out of it.");
+
+    let fields = fields
+        .iter()
+        .filter(|field| !field.getters.is_empty())
+        .collect::<Vec<_>>();
+
+    let cfgs = fields
+        .iter()
+        .map(|field| field.cfg_attrs())
+        .collect::<Vec<_>>();
+    let docs = fields.iter().map(|field| {
+        field.docs_or(format!(
+            "The `{}` field moved out of [`{ident}`].",
+            field.name()
+        ))
+    });
+    let non_exhaustive = non_exhaustive(item);
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let tys = fields.iter().map(|field| &field.ty);
+
+    Ok(quote! {
+        #[doc = #doc]
+        #non_exhaustive
+        #vis struct #fields_ident {
+            #(
+                #(#cfgs)*
+                #(#docs)*
+                #vis #idents: #tys,
+            )*
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::From<#ident> for #fields_ident {
+            fn from(value: #ident) -> Self {
+                Self {
+                    #(
+                        #(#cfgs)*
+                        #idents: value.#idents,
+                    )*
+                }
+            }
+        }
+    })
+}
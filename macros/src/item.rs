@@ -0,0 +1,35 @@
+use proc_macro2::Span;
+use syn::{Attribute, Fields, ItemStruct};
+
+/// Check that `item` can be mirrored by the types `option` generates from its fields,
+/// which requires named fields and, unless `generics` is set, no generic parameters.
+pub fn require_named_fields(
+    item: &ItemStruct,
+    option: &str,
+    span: Span,
+    generics: bool,
+) -> syn::Result<()> {
+    if !generics && !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            format!("`{option}` is not supported on generic structs"),
+        ));
+    }
+
+    if !matches!(item.fields, Fields::Named(_)) {
+        return Err(syn::Error::new(
+            span,
+            format!("`{option}` requires a struct with named fields"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// The struct's `#[non_exhaustive]` attribute, if any, for the generated types naming its
+/// fields, since new fields would otherwise break exhaustive patterns on them downstream.
+pub fn non_exhaustive(item: &ItemStruct) -> Option<&Attribute> {
+    item.attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("non_exhaustive"))
+}
@@ -1,6 +1,42 @@
+mod args;
+mod bounds;
+mod case;
+mod constructor;
+mod diff;
+mod display;
+mod dump;
+mod env;
+mod errors;
+mod ffi;
+mod field;
+mod from_tuple;
+mod getter;
+mod index;
+mod into_fields;
+mod item;
+mod mask;
+mod names;
+mod serde_accessors;
+mod setter;
+mod snapshot;
+mod try_from_int;
+mod types;
+mod update;
+mod visibility;
+
 use proc_macro::TokenStream;
-use quote::{format_ident, quote};
-use syn::{parse_macro_input, ItemStruct};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::{parse::Parser, parse_macro_input, parse_quote_spanned, Ident, Item, ItemStruct, Meta};
+
+use crate::{
+    args::Args,
+    case::Case,
+    errors::Errors,
+    field::{read_only_error, Field},
+    getter::is_read_only,
+    names::Names,
+    types::generic_arg,
+};
 
 /// Generate getters and setters procedurally.
 ///
@@ -75,143 +111,5754 @@ use syn::{parse_macro_input, ItemStruct};
 ///     assert_eq!(foo.a(), &42); // this method doesn't exist
 /// }
 /// ```
-#[proc_macro_attribute]
-pub fn ters(_args: TokenStream, tokens: TokenStream) -> TokenStream {
-    let item = parse_macro_input!(tokens as ItemStruct);
-
-    ters_inner(item).into()
-}
-
-fn ters_inner(mut item: ItemStruct) -> proc_macro2::TokenStream {
-    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
-
-    let mut fields = Vec::new();
-
-    for field in item.fields.iter_mut() {
-        let mut get = false;
-        let mut set = false;
-
-        field.attrs.retain(|attr| {
-            if attr.path().is_ident("get") {
-                get = true;
-                false
-            } else if attr.path().is_ident("set") {
-                set = true;
-                false
-            } else {
-                true
-            }
-        });
-
-        fields.push((
-            field.ident.clone().unwrap(),
-            field.ty.clone(),
-            get,
-            set,
-            field
-                .attrs
-                .iter()
-                .filter(|attr| {
-                    attr.path()
-                        .get_ident()
-                        .map(|ident| ident == "doc")
-                        .is_some_and(|is_doc| is_doc)
-                })
-                .cloned()
-                .collect::<Vec<_>>(),
-        ));
-    }
-
-    let accessors = fields
-        .iter()
-        .filter_map(|(ident, ty, get, set, docs)| {
-            let set_ident = format_ident!("set_{ident}");
-            let str_ident = ident.to_string();
-
-            let mut body = quote! {};
-
-            if *get {
-                body.extend(quote! {
-                    #[doc = "Getter for `"]
-                    #[doc = #str_ident]
-                    #[doc = "`.\n\n"]
-                    #(#docs)*
-                    #[inline]
-                    pub fn #ident(&self) -> &#ty {
-                        &self.#ident
-                    }
-                });
-            }
-
-            if *set {
-                body.extend(quote! {
-                    #[doc = "Setter for `"]
-                    #[doc = #str_ident]
-                    #[doc = "`.\n\n"]
-                    #(#docs)*
-                    #[inline]
-                    pub fn #set_ident(&mut self, value: #ty) {
-                        self.#ident = value;
-                    }
-                });
-            }
-
-            (!body.is_empty()).then_some(body)
-        })
-        .collect::<Vec<_>>();
-
-    let ident = &item.ident;
-
-    let impl_ = (!accessors.is_empty()).then_some(quote! {
-        impl #impl_generics #ident #ty_generics #where_clause {
-            #(
-                #accessors
-            )*
-        }
-    });
-
-    quote! {
-        #item
-        #impl_
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use quote::quote;
-    use syn::parse_quote;
-
-    use crate::ters_inner;
-
-    #[test]
-    fn docs() {
-        let input = parse_quote! {
-            struct Foo {
-                /// Baz.
-                #[get]
-                bar: u8,
-            }
-        };
-
-        let expected = quote! {
-            struct Foo {
-                /// Baz.
-                bar: u8,
-            }
-
-            impl Foo {
-                #[doc = "Getter for `"]
-                #[doc = "bar"]
-                #[doc = "`.\n\n"]
-                /// Baz.
-                #[inline]
-                pub fn bar(&self) -> &u8 {
-                    &self.bar
+///
+/// Pass `normalize = path` to `#[set]` to transform values before they are stored.
+/// The function must take and return the field's type.
+/// ```ignore
+/// use ters::ters;
+///
+/// fn trim(value: &'static str) -> &'static str {
+///     value.trim()
+/// }
+///
+/// #[ters]
+/// struct Foo {
+///     #[get]
+///     #[set(normalize = trim)]
+///     a: &'static str,
+/// }
+///
+/// fn normalize() {
+///     let mut foo = Foo { a: "" };
+///     foo.set_a("  bar ");
+///     assert_eq!(foo.a(), &"bar");
+/// }
+/// ```
+///
+/// Pass `propagate_lints` to `#[ters]` to copy lint attributes (`#[allow]`, `#[expect]`, `#[warn]`, `#[deny]`, and `#[forbid]`) from fields onto their accessors.
+/// An `#[expect]` is copied as an `#[allow]`, since the accessor need not trigger the expected lint itself.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(propagate_lints)]
+/// struct Foo {
+///     #[get]
+///     #[allow(clippy::struct_field_names)]
+///     foo_a: i32,
+/// }
+/// ```
+///
+/// Generated accessors are implemented on the struct's type, which lives in a separate namespace from values.
+/// Local variables sharing the struct's name do not affect the generated code.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get]
+///     a: i32,
+/// }
+///
+/// #[allow(non_snake_case)]
+/// fn shadowed() {
+///     let Foo = Foo { a: 42 };
+///     assert_eq!(Foo.a(), &42);
+/// }
+/// ```
+///
+/// `#[ters]` can only be applied to structs, and to enums with `try_from_int`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// enum Foo {
+///     A,
+///     B,
+/// }
+/// ```
+///
+/// Pass `format_with = path` to `#[get]` to generate a `_display` getter which formats the field with the given function.
+/// The function takes a reference to the field and returns something implementing `Display`.
+/// ```ignore
+/// use core::fmt::{self, Display, Formatter};
+///
+/// use ters::ters;
+///
+/// struct Celsius<'a>(&'a f32);
+///
+/// impl Display for Celsius<'_> {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         write!(f, "{}°C", self.0)
+///     }
+/// }
+///
+/// fn celsius(value: &f32) -> Celsius<'_> {
+///     Celsius(value)
+/// }
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(format_with = celsius)]
+///     a: f32,
+/// }
+///
+/// fn format_with() {
+///     let foo = Foo { a: 21.5 };
+///     assert_eq!(foo.a_display().to_string(), "21.5°C");
+/// }
+/// ```
+///
+/// Generated accessors are `pub`, but remain bounded by the visibility of the struct: they can't be reached from anywhere the struct itself is inaccessible.
+/// ```ignore
+/// mod inner {
+///     use ters::ters;
+///
+///     #[ters]
+///     pub(super) struct Foo {
+///         #[get]
+///         a: i32,
+///     }
+/// }
+///
+/// fn accessible(foo: &inner::Foo) -> &i32 {
+///     foo.a()
+/// }
+///
+/// mod elsewhere {
+///     mod inner {
+///         use ters::ters;
+///
+///         #[ters]
+///         struct Foo {
+///             #[get]
+///             a: i32,
+///         }
+///     }
+///
+///     fn inaccessible(foo: &inner::Foo) -> &i32 {
+///         foo.a() // `Foo` is private
+///     }
+/// }
+/// ```
+///
+/// Multiple `#[ters]` applications on one struct are merged, so options can be enabled conditionally with `#[cfg_attr]` while still generating a single impl block.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// #[cfg_attr(feature = "lints", ters(propagate_lints))]
+/// struct Foo {
+///     #[get]
+///     a: i32,
+/// }
+/// ```
+///
+/// Pass `on_read = hook` to `#[get]` to call a function whenever the getter is invoked.
+/// Closures taking an argument receive the field name as a `&'static str`.
+/// ```ignore
+/// use ters::ters;
+///
+/// fn log_access() {}
+///
+/// fn record_access(_name: &'static str) {}
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(on_read = log_access)]
+///     a: i32,
+///     #[get(on_read = |name| record_access(name))]
+///     b: bool,
+/// }
+/// ```
+///
+/// Pass `name = "..."` to `#[get]` or `#[set]` to override the name of the generated method.
+/// Names must be valid identifiers; keywords can be used through raw identifiers (`"r#type"`).
+/// Leading and trailing underscores and non-ASCII identifiers are accepted just as rustc accepts them, e.g. `"_len"` or `"größe"`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(name = "value")]
+///     #[set(name = "replace")]
+///     a: i32,
+/// }
+///
+/// fn rename() {
+///     let mut foo = Foo { a: 42 };
+///     foo.replace(31);
+///     assert_eq!(foo.value(), &31);
+/// }
+/// ```
+///
+/// Pass `builder` to `#[set]` to generate a `with_` setter which takes and returns `self` for chaining.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get]
+///     #[set(builder)]
+///     a: i32,
+///     #[get]
+///     #[set(builder)]
+///     b: bool,
+/// }
+///
+/// fn builder() {
+///     let foo = Foo { a: 0, b: false }.with_a(42).with_b(true);
+///     assert_eq!(foo.a(), &42);
+///     assert_eq!(foo.b(), &true);
+/// }
+/// ```
+///
+/// When combined with `pin_project`, place `#[ters]` above `#[pin_project]`.
+/// `#[pin]` attributes are left in place for `pin_project`, and requesting accessors for pinned fields is an error since they are accessed through projections instead.
+///
+/// Pass `copy` or `clone` to `#[get]` to return the field by value rather than by reference.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(copy)]
+///     a: i32,
+///     #[get(clone)]
+///     b: String,
+/// }
+///
+/// fn by_value() {
+///     let foo = Foo { a: 42, b: "bar".into() };
+///     let a: i32 = foo.a();
+///     let b: String = foo.b();
+/// }
+/// ```
+///
+/// The field's type must implement the corresponding trait, which is reported against the field.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(copy)]
+///     a: String, // `String` is not `Copy`
+/// }
+/// ```
+///
+/// Getters are `pub`, so a field of a private type leaks that type through the public interface (which rustc reports via the `private_interfaces` lint); choosing accessors appropriate for the field's type is the user's responsibility.
+/// Pass `opaque` to `#[get]` to return the field as `&dyn Any` instead, hiding its concrete type while still allowing downcasting.
+/// ```ignore
+/// use core::any::Any;
+///
+/// use ters::ters;
+///
+/// struct Secret(i32);
+///
+/// #[ters]
+/// pub struct Foo {
+///     #[get(opaque)]
+///     a: Secret,
+/// }
+///
+/// fn opaque(foo: &Foo) {
+///     let any: &dyn Any = foo.a();
+///     assert!(any.downcast_ref::<Secret>().is_some());
+/// }
+/// ```
+///
+/// Pass `matches` with a pattern to `#[get]` to generate a predicate testing the field against it with `core::matches!`.
+/// A `name` is required, and a field may have several predicates.
+/// ```ignore
+/// use ters::ters;
+///
+/// enum State {
+///     Idle,
+///     Connected { peer: u16 },
+///     Closed,
+/// }
+///
+/// #[ters]
+/// struct Conn {
+///     #[get(matches = State::Connected { .. }, name = "is_connected")]
+///     #[get(matches = State::Idle | State::Closed, name = "is_inactive")]
+///     state: State,
+/// }
+///
+/// fn matches(conn: &Conn) {
+///     let connected: bool = conn.is_connected();
+///     let inactive: bool = conn.is_inactive();
+/// }
+/// ```
+///
+/// Pass `sized` to `#[get]` to bound the generated getter by `where Self: Sized`, so that it is only available on sized instantiations of the struct.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo<T: ?Sized> {
+///     #[get(sized)]
+///     a: i32,
+///     b: T,
+/// }
+///
+/// fn sized(foo: &Foo<[u8; 2]>) {
+///     let a: &i32 = foo.a();
+/// }
+/// ```
+///
+/// Pass `info` to `#[ters]` to list the methods it generates in a compile-time warning, which is handy when inspecting the expansion from an IDE or build log.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(info)] // warning: `#[ters]` generates these methods for `Foo`: `a`, `set_a`
+/// struct Foo {
+///     #[get]
+///     #[set]
+///     a: i32,
+/// }
+/// ```
+///
+/// Pass `ok`, `err`, or `is_ok` to `#[get]` on a `Result<T, E>` field to generate `_ok` and `_err` getters returning `Option<&T>` and `Option<&E>`, or an `_is_ok` predicate.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(ok)]
+///     #[get(err)]
+///     #[get(is_ok)]
+///     parse: Result<i32, String>,
+/// }
+///
+/// fn result(foo: &Foo) {
+///     let ok: Option<&i32> = foo.parse_ok();
+///     let err: Option<&String> = foo.parse_err();
+///     let is_ok: bool = foo.parse_is_ok();
+/// }
+/// ```
+///
+/// The field's type must be written as `Result<T, E>`, since type aliases cannot be seen through by the macro.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(ok)]
+///     a: Option<i32>, // not a `Result`
+/// }
+/// ```
+///
+/// Pass `unpack` to `#[set]` on a field holding a tuple of two or more elements to take its elements as separate parameters, named `a`, `b`, and so on unless names are given as `unpack(x, y)`.
+/// Combined with `#[get(copy)]`, tuples of `Copy` elements can be read back by value.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(copy)]
+///     #[set(unpack)]
+///     pos: (f32, f32),
+///     #[set(unpack(start, end))]
+///     range: (u32, u32),
+/// }
+///
+/// fn unpack(foo: &mut Foo) {
+///     foo.set_pos(1.0, 2.0);
+///     foo.set_range(0, 10);
+///     let (x, y) = foo.pos();
+/// }
+/// ```
+///
+/// With the `async` feature enabled, pass `async_fn` to `#[get]` to generate an `async fn` getter for APIs expecting all accessors to be awaited.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(async_fn)]
+///     a: i32,
+/// }
+///
+/// async fn async_fn(foo: &Foo) {
+///     let a: &i32 = foo.a().await;
+/// }
+/// ```
+///
+/// Pass `constructor` to `#[ters]` to generate a `new` constructor taking every field.
+/// With `skip_none` as well, `Option` fields are left out and initialized to `None`, unless marked `#[include_in_constructor]`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(constructor, skip_none)]
+/// struct Foo {
+///     a: i32,
+///     b: Option<i32>,
+///     #[include_in_constructor]
+///     c: Option<i32>,
+/// }
+///
+/// fn constructor() -> Foo {
+///     Foo::new(1, Some(3))
+/// }
+/// ```
+///
+/// Pass `bounded` with a range to `#[set]` to debug-assert that values lie within it; unlike clamping, release builds store the value unchecked.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[set(bounded = "0.0..=1.0")]
+///     opacity: f32,
+/// }
+///
+/// fn bounded(foo: &mut Foo) {
+///     foo.set_opacity(0.5);
+/// }
+/// ```
+///
+/// For tooling that needs to know which accessors exist, set the `TERS_DUMP_METHODS` environment variable to a directory while building.
+/// Each `#[ters]` struct then writes its generated methods to `{dir}/{Struct}.json`, and each `#[derive(Getters)]` or `#[derive(Setters)]` to `{dir}/{Struct}.Getters.json` or `{dir}/{Struct}.Setters.json`, in the form `{"struct":"Foo","methods":[{"name":"a","signature":"pub fn a(&self) -> &i32"}]}`.
+/// Structs of the same name overwrite each other's listings, and since rustc does not track the variable, changing it requires a rebuild of the crates involved.
+///
+/// Pass `vis` to `#[get]` or `#[set]` to override the `pub` visibility of the generated method, e.g. `vis = "pub(crate)"`, or `vis = ""` for a private one. The visibility may also be written bare, as in `vis = pub(crate)`.
+/// As with any method, the visibility is relative to the module defining the struct, and re-exporting the struct elsewhere does not widen it.
+/// ```ignore
+/// mod inner {
+///     mod nested {
+///         use ters::ters;
+///
+///         #[ters]
+///         pub struct Foo {
+///             #[get(vis = "pub(super)")]
+///             a: i32,
+///         }
+///
+///         impl Foo {
+///             pub fn new() -> Self {
+///                 Self { a: 42 }
+///             }
+///         }
+///     }
+///
+///     pub use nested::Foo;
+/// }
+///
+/// fn reexported() {
+///     let foo = inner::Foo::new();
+///     let a = foo.a(); // only visible within `inner`
+/// }
+/// ```
+///
+/// Search aliases declared with `#[doc(alias = "...")]` on a field are forwarded to its accessors, prefixed with `get_` or `set_`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[doc(alias = "colour")]
+///     #[get] // searchable as `get_colour`
+///     #[set] // searchable as `set_colour`
+///     color: u32,
+/// }
+/// ```
+///
+/// With the `std` feature enabled, pass `arc_ptr` to `#[get]` on an `Arc<T>` field to generate a `_ptr` getter returning the `*const T` to the data it manages.
+/// ```ignore
+/// use std::sync::Arc;
+///
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(arc_ptr)]
+///     a: Arc<i32>,
+/// }
+///
+/// fn arc_ptr(foo: &Foo) {
+///     let ptr: *const i32 = foo.a_ptr();
+/// }
+/// ```
+///
+/// Pass `discriminant` to `#[get]` on an enum field to generate a `_discriminant` getter returning its `core::mem::Discriminant`, for comparisons ignoring payload data.
+/// ```ignore
+/// use core::mem::Discriminant;
+///
+/// use ters::ters;
+///
+/// enum State {
+///     Idle,
+///     Busy(u8),
+/// }
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(discriminant)]
+///     state: State,
+/// }
+///
+/// fn discriminant(foo: &Foo) {
+///     let discriminant: Discriminant<State> = foo.state_discriminant();
+/// }
+/// ```
+///
+/// Since a method is never more accessible than its type, a `pub` getter on a `pub(super)` struct is effectively `pub(super)`.
+/// Pass `visibility_check` to `#[ters]` to reject accessors declared more visible than the struct, so that their declared visibility is never misleading.
+/// ```ignore
+/// mod inner {
+///     use ters::ters;
+///
+///     #[ters(visibility_check)]
+///     pub(super) struct Foo {
+///         #[get] // `pub`, but `Foo` is only `pub(super)`
+///         a: i32,
+///     }
+/// }
+/// ```
+///
+/// With the `std` feature enabled, pass `push_back` or `push_front` to `#[set]` on a `VecDeque<T>` field to generate a setter pushing elements onto that end, prefixed `push_back_` or `push_front_`.
+/// ```ignore
+/// use std::collections::VecDeque;
+///
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[set(push_back)]
+///     #[set(push_front)]
+///     queue: VecDeque<i32>,
+/// }
+///
+/// fn push(foo: &mut Foo) {
+///     foo.push_back_queue(2);
+///     foo.push_front_queue(1);
+/// }
+/// ```
+///
+/// Likewise, pass `pop_front` or `pop_back` to `#[get]` on a `VecDeque<T>` field to generate a getter popping elements off that end, prefixed `pop_front_` or `pop_back_`.
+/// These take `&mut self`, since popping modifies the field.
+/// ```ignore
+/// use std::collections::VecDeque;
+///
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(pop_front)]
+///     #[get(pop_back)]
+///     queue: VecDeque<i32>,
+/// }
+///
+/// fn pop(foo: &mut Foo) {
+///     let front: Option<i32> = foo.pop_front_queue();
+///     let back: Option<i32> = foo.pop_back_queue();
+/// }
+/// ```
+///
+/// Pass `transmute_to` with a type to `#[get]` to generate an `unsafe` getter reinterpreting the field's bits as that type, named `{field}_as_{type}` unless a `name` is given.
+/// The field must be `Copy`, and the sizes of both types must match.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(transmute_to = f32)]
+///     bits: u32,
+/// }
+///
+/// fn transmute_to(foo: &Foo) {
+///     // SAFETY: every bit pattern is a valid `f32`
+///     let value: f32 = unsafe { foo.bits_as_f32() };
+/// }
+/// ```
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(transmute_to = f64)] // `u32` and `f64` differ in size
+///     bits: u32,
+/// }
+/// ```
+///
+/// Pass `passthrough` to `#[ters]` to generate nothing at all, re-emitting the struct with just the accessor attributes removed.
+/// This is useful when `#[ters]` is only wanted in some configurations.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[cfg_attr(feature = "accessors", ters)]
+/// #[cfg_attr(not(feature = "accessors"), ters(passthrough))]
+/// struct Foo {
+///     #[get]
+///     a: i32,
+/// }
+/// ```
+///
+/// Pass `read_only` to `#[get]` to declare that a field is intentionally not settable through accessors; adding a `#[set]` to it is then an error.
+/// `#[ters(set)]` leaves such fields out instead.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(read_only)]
+///     #[set] // conflicts with `read_only`
+///     a: i32,
+/// }
+/// ```
+///
+/// Pass `into_fields` to `#[ters]` to generate a `{Struct}Fields` struct holding the fields which have getters, along with a `From` impl moving them out of the struct.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(into_fields)]
+/// pub struct Foo {
+///     #[get]
+///     a: i32,
+///     b: i32,
+///     #[get]
+///     c: String,
+/// }
+///
+/// fn into_fields(foo: Foo) {
+///     let FooFields { a, c } = foo.into();
+/// }
+/// ```
+///
+/// If the struct is `#[must_use]`, so are its getters, while setters are left as they are.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// #[must_use]
+/// struct Foo {
+///     #[get] // `#[must_use]`
+///     #[set]
+///     a: i32,
+/// }
+/// ```
+///
+/// With the `log` feature enabled, pass `log_changes` to `#[set]` to log the old and new values with `log::debug!` whenever the setter is called.
+/// The feature pulls in the `log` crate for the generated code, and the field's type must implement `Debug`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[set(log_changes)]
+///     a: i32,
+/// }
+///
+/// fn log_changes(foo: &mut Foo) {
+///     foo.set_a(42); // logs "a: 0 -> 42"
+/// }
+/// ```
+///
+/// Pass `hash_value` to `#[get]` to generate a `_hash` getter returning the field's hash as a `u64`, which is useful for detecting changes without keeping the old value.
+/// The hash is computed with `DefaultHasher`, which requires the `std` feature, or with the `Default` hasher given as `hash_value = MyHasher`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(hash_value)]
+///     a: String,
+/// }
+///
+/// fn hash_value(foo: &Foo) {
+///     let hash: u64 = foo.a_hash();
+/// }
+/// ```
+///
+/// Generic structs, including ones with const generic parameters and defaults, have their accessors generated for every instantiation.
+/// Rust has no structs nested in `impl` blocks, and structs nested in generic functions cannot use the function's parameters, so the struct's own parameters are the only ones to consider.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo<const N: usize = 4> {
+///     #[get]
+///     a: [u8; N],
+/// }
+///
+/// fn const_generics(foo: &Foo<2>) {
+///     let a: &[u8; 2] = foo.a();
+/// }
+/// ```
+///
+/// Generic structs, including ones with const generic parameters and defaults, have their accessors generated for every instantiation.
+/// Rust has no structs nested in `impl` blocks, and structs nested in generic functions cannot use the function's parameters, so the struct's own parameters are the only ones to consider.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo<const N: usize = 4> {
+///     #[get]
+///     a: [u8; N],
+/// }
+///
+/// fn const_generics(foo: &Foo<2>) {
+///     let a: &[u8; 2] = foo.a();
+/// }
+/// ```
+///
+/// Getters and setters can also be generated with derive macros, which leave the struct untouched. Struct-level `#[ters(...)]` options are not available this way.
+///
+/// ```ignore
+/// use ters::{Getters, Setters};
+///
+/// #[derive(Getters, Setters)]
+/// struct Foo {
+///     #[get]
+///     #[set]
+///     a: i32,
+/// }
+/// ```
+///
+/// Inherent methods take precedence over trait methods, so a getter named like a standard trait method, such as `clone`, silently changes what `foo.clone()` calls.
+/// Pass `conflict_check` to `#[ters]` to reject accessors shadowing methods of standard traits.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(conflict_check)]
+/// #[derive(Clone)]
+/// struct Foo {
+///     #[get(name = "clone")] // shadows `Clone::clone`
+///     a: i32,
+/// }
+/// ```
+///
+/// Pass `mut` to `#[get]` to generate a `_mut` getter returning a mutable reference to the field.
+/// To generate both the shared and mutable getters with one annotation, pass `mutable_alias` (or its synonyms `with_mut` and `mut_pair`) instead.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(mut)]
+///     a: i32,
+///     #[get(mutable_alias)] // `b` and `b_mut`
+///     b: i32,
+/// }
+///
+/// fn mutable(foo: &mut Foo) {
+///     *foo.a_mut() += 1;
+///     *foo.b_mut() = *foo.b() + 1;
+/// }
+/// ```
+///
+/// Pass `conditional` to `#[set]` to only store values satisfying a predicate, which is given a reference to the new value.
+/// The setter returns whether the value was stored.
+/// ```ignore
+/// use ters::ters;
+///
+/// fn is_even(value: &i32) -> bool {
+///     value % 2 == 0
+/// }
+///
+/// #[ters]
+/// struct Foo {
+///     #[set(conditional = is_even)]
+///     a: i32,
+/// }
+///
+/// fn conditional(foo: &mut Foo) {
+///     assert!(foo.set_a(2));
+///     assert!(!foo.set_a(3));
+/// }
+/// ```
+///
+/// Crates re-exporting the attribute, such as with `pub use ters::ters;`, can be used just the same, since macros are resolved by what they are rather than by their path.
+/// Further applications are merged by name, so keep the re-export named `ters`.
+/// ```ignore
+/// mod reexport {
+///     pub use ters::ters;
+/// }
+///
+/// #[reexport::ters]
+/// #[reexport::ters(constructor)]
+/// struct Foo {
+///     #[get]
+///     a: i32,
+/// }
+///
+/// fn reexported() {
+///     let foo = Foo::new(1);
+///     assert_eq!(foo.a(), &1);
+/// }
+/// ```
+///
+/// Pass `idempotent` to `#[set]` to skip storing values equal to the current one, avoiding spurious updates in observer patterns.
+/// The setter returns whether the value changed, and requires the field to implement `PartialEq`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[set(idempotent)]
+///     a: i32,
+/// }
+///
+/// fn idempotent(foo: &mut Foo) {
+///     assert!(foo.set_a(1));
+///     assert!(!foo.set_a(1));
+/// }
+/// ```
+///
+/// With the `std` feature enabled, pass `debug_str` to `#[get]` to generate a `_debug_str` getter returning the field formatted with `{:?}` as a `String`, for logging and diagnostics.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get(debug_str)]
+///     a: Option<i32>,
+/// }
+///
+/// fn debug_str(foo: &Foo) {
+///     let debug: String = foo.a_debug_str();
+/// }
+/// ```
+///
+/// Structs from other crates cannot be annotated, but can be wrapped in a newtype which is.
+/// Accessors are only generated for the wrapper's own fields, since the macro cannot see the methods (or private fields) of the wrapped type; forward to the wrapped type by hand where needed.
+/// ```ignore
+/// use core::time::Duration;
+///
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Timeout {
+///     #[get(copy)]
+///     #[set]
+///     duration: Duration,
+/// }
+///
+/// impl Timeout {
+///     fn as_millis(&self) -> u128 {
+///         self.duration.as_millis()
+///     }
+/// }
+///
+/// fn newtype(timeout: &mut Timeout) {
+///     timeout.set_duration(Duration::from_secs(1));
+///     assert_eq!(timeout.as_millis(), 1000);
+/// }
+/// ```
+///
+/// Pass `version = 2` to `#[ters]` to opt into changed defaults without waiting for a breaking release; the current defaults are `version = 1`.
+/// When upgrading to `version = 2`, note that generated methods, including the constructor, are private unless given a `vis`.
+/// ```ignore
+/// mod inner {
+///     use ters::ters;
+///
+///     #[ters(version = 2)]
+///     pub struct Foo {
+///         #[get] // private
+///         a: i32,
+///         #[get(vis = "pub")]
+///         b: i32,
+///     }
+/// }
+/// ```
+///
+/// Pass `field_name_str` to `#[get]` to generate an `_as_str` getter returning the field's name as a `&'static str`, for reflection-like uses needing the name alongside the value.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Foo {
+///     #[get]
+///     #[get(field_name_str)]
+///     a: i32,
+/// }
+///
+/// fn field_name_str(foo: &Foo) {
+///     assert_eq!(foo.a_as_str(), "a");
+/// }
+/// ```
+///
+/// Pass `setter_prefix` to `#[ters]` to replace the `set_` prefix of setter names.
+/// An empty prefix names setters after their fields alone, which is rejected for fields which also have a getter of that name.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(setter_prefix = "put_")]
+/// struct Foo {
+///     #[set]
+///     a: i32,
+/// }
+///
+/// fn setter_prefix(foo: &mut Foo) {
+///     foo.put_a(1);
+/// }
+/// ```
+///
+/// With the `ffi` feature enabled, pass `fn_ptr` to `#[set]` on a `Copy` field to generate a `_SETTER` constant holding an `unsafe extern "C" fn(*mut Foo, T)`, for building C function tables.
+/// It stores its argument into the field of the struct pointed to, which must be valid for writes.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// #[repr(C)]
+/// struct Foo {
+///     #[set(fn_ptr)]
+///     a: i32,
+/// }
+///
+/// fn fn_ptr(foo: &mut Foo) {
+///     let set: unsafe extern "C" fn(*mut Foo, i32) = Foo::A_SETTER;
+///     unsafe { set(foo, 1) };
+/// }
+/// ```
+///
+/// Likewise, pass `fn_ptr` to `#[get]` on a `Copy` field to generate a `_GETTER` constant holding an `unsafe extern "C" fn(*const Foo) -> T`, returning a copy of the field of the struct pointed to.
+/// Together, these build C-compatible function tables purely from annotations.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// #[repr(C)]
+/// struct Foo {
+///     #[get(fn_ptr)]
+///     #[set(fn_ptr)]
+///     a: i32,
+/// }
+///
+/// #[repr(C)]
+/// struct VTable {
+///     get: unsafe extern "C" fn(*const Foo) -> i32,
+///     set: unsafe extern "C" fn(*mut Foo, i32),
+/// }
+///
+/// const VTABLE: VTable = VTable {
+///     get: Foo::A_GETTER,
+///     set: Foo::A_SETTER,
+/// };
+/// ```
+///
+/// Instead of annotating fields, list the fields to generate accessors for with `get = [...]` and `set = [...]` in `#[ters]`, which is more compact when only a few fields of a large struct need them.
+/// Listed fields are treated as if annotated with a plain `#[get]` or `#[set]`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(get = [name, age], set = [age])]
+/// struct Person {
+///     name: String,
+///     age: u8,
+///     id: u32,
+/// }
+///
+/// fn field_lists(person: &mut Person) {
+///     person.set_age(*person.age() + 1);
+/// }
+/// ```
+///
+/// Pass `get` or `set` to `#[ters]` without a field list to generate accessors for every field not annotated already, leaving out the fields listed in `exclude = [...]`. This needs named fields, so it is rejected on tuple structs.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(get, set, exclude = [cache])]
+/// struct Foo {
+///     #[get(copy)] // instead of the plain `#[get]`
+///     a: i32,
+///     b: bool,
+///     cache: Option<u64>,
+/// }
+///
+/// fn all_fields(foo: &mut Foo) {
+///     foo.set_b(foo.a() > 0);
+/// }
+/// ```
+///
+/// Pass `zip_adjacent` to `#[get]` to generate a getter returning references to the field and the next one in declaration order, named by concatenating their names.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Vector {
+///     #[get(zip_adjacent)]
+///     x: f64,
+///     y: f64,
+///     z: f64,
+/// }
+///
+/// fn zip_adjacent(v: &Vector) {
+///     let (x, y): (&f64, &f64) = v.xy();
+/// }
+/// ```
+///
+/// Pass `generate_builder_fn` to `#[ters]` to generate the conventional `builder` entry point, returning the `Default` value of a `FooBuilder` type defined alongside the struct.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(generate_builder_fn)]
+/// struct Foo {
+///     a: i32,
+/// }
+///
+/// #[derive(Default)]
+/// struct FooBuilder {
+///     a: i32,
+/// }
+///
+/// impl FooBuilder {
+///     fn build(self) -> Foo {
+///         Foo { a: self.a }
+///     }
+/// }
+///
+/// fn builder() -> Foo {
+///     Foo::builder().build()
+/// }
+/// ```
+///
+/// Pass `impl_display` to `#[ters]` to implement `Display`, showing the fields which have getters like `Debug` does, but with their `Display` representations.
+/// The types of those fields must implement `Display`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(impl_display)]
+/// struct Person {
+///     #[get]
+///     name: String,
+///     #[get]
+///     age: u8,
+/// }
+///
+/// fn impl_display(person: &Person) {
+///     println!("{person}"); // Person { name: Ferris, age: 8 }
+/// }
+/// ```
+///
+/// Fields which are already `pub` get accessors all the same, for consistency and for method syntax where direct field access would also work.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// pub struct Foo {
+///     #[get]
+///     pub a: u32,
+/// }
+///
+/// fn pub_fields(foo: &Foo) {
+///     assert_eq!(foo.a(), &foo.a);
+/// }
+/// ```
+///
+/// Pass `atomic_fetch_update` to `#[set]` on a field of an atomic type to generate a `fetch_update_` method wrapping its `fetch_update`, which updates the value with a closure through a shared reference.
+/// ```ignore
+/// use core::sync::atomic::{AtomicU32, Ordering};
+///
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Counter {
+///     #[set(atomic_fetch_update)]
+///     count: AtomicU32,
+/// }
+///
+/// fn atomic_fetch_update(counter: &Counter) {
+///     let previous = counter.fetch_update_count(Ordering::SeqCst, Ordering::SeqCst, |count| count.checked_add(1));
+/// }
+/// ```
+///
+/// Pass `const_value` to `#[get]` to generate an associated `const fn` returning the given constant expression instead of reading the field, such as for fields mirroring a const generic parameter.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct SizedBuffer<const N: usize> {
+///     #[get(const_value = N)]
+///     len: usize,
+/// }
+///
+/// const LEN: usize = SizedBuffer::<4>::len();
+/// ```
+///
+/// Other macros can include accessor generation as one step by emitting `#[ters::ters]` on the structs they generate.
+/// The generation logic itself is not exposed as a library function, since proc-macro crates can only export macros.
+/// ```ignore
+/// macro_rules! record {
+///     ($name:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+///         #[ters::ters(get)]
+///         struct $name {
+///             $($field: $ty),*
+///         }
+///     };
+/// }
+///
+/// record!(Point { x: f32, y: f32 });
+///
+/// fn generated(point: &Point) -> f32 {
+///     point.x() + point.y()
+/// }
+/// ```
+///
+/// Pass `impl_from_tuple` to `#[ters]` on a tuple struct to implement `From` both from and into a tuple of its fields, in order. Accessors of tuple struct fields must be given a `name`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(impl_from_tuple)]
+/// struct Point(#[get(copy, name = "x")] f32, #[get(copy, name = "y")] f32);
+///
+/// fn impl_from_tuple() {
+///     let point = Point::from((1.0, 2.0));
+///     assert_eq!(point.x(), 1.0);
+///
+///     let (x, y) = point.into();
+///     assert_eq!((x, y), (1.0, 2.0));
+/// }
+/// ```
+///
+/// Options to `#[ters]` may be combined freely and split across lines. Pass `vis` to set the visibility of accessors not given their own, written as for `#[get]` and `#[set]`, and `rename_all` to convert the generated method names to `camelCase` or `PascalCase` (or keep `snake_case`), except those given a `name`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(
+///     get,
+///     set,
+///     vis = "pub(crate)",
+///     setter_prefix = "with_",
+///     rename_all = "camelCase",
+/// )]
+/// struct Foo {
+///     bar_baz: u32,
+/// }
+///
+/// fn rename_all(mut foo: Foo) {
+///     foo.withBarBaz(42);
+///     assert_eq!(foo.barBaz(), &42);
+/// }
+/// ```
+///
+/// Pass `phantom_skip` alongside `get` or `set` to leave out the fields of type `PhantomData<_>`, which hold nothing worth accessing.
+/// ```ignore
+/// use core::marker::PhantomData;
+///
+/// use ters::ters;
+///
+/// #[ters(get, phantom_skip)]
+/// struct Id<T> {
+///     value: u64,
+///     _marker: PhantomData<T>,
+/// }
+///
+/// fn phantom_skip(id: &Id<String>) {
+///     println!("{}", id.value());
+/// }
+/// ```
+///
+/// Pass `debug_assert_some` to `#[get]` on an `Option<T>` field to return `Option<&T>`, debug-asserting that the field is `Some`. This catches fields read before they are initialized in debug builds, at no cost in release builds.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// struct Connection {
+///     #[get(debug_assert_some)]
+///     session: Option<u64>,
+/// }
+///
+/// fn debug_assert_some(connection: &Connection) {
+///     // panics in debug builds if `session` is `None`
+///     println!("{:?}", connection.session());
+/// }
+/// ```
+///
+/// Pass `generate_index` to `#[ters]` to generate a `{Struct}Field` enum with a variant for each field which has a getter, along with an `Index` impl selecting the field by variant. Since `Index` has a single `Output`, the fields must share a type.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(generate_index)]
+/// struct Rgb {
+///     #[get]
+///     red: u8,
+///     #[get]
+///     green: u8,
+///     #[get]
+///     blue: u8,
+/// }
+///
+/// fn generate_index(rgb: &Rgb) {
+///     for channel in [RgbField::Red, RgbField::Green, RgbField::Blue] {
+///         println!("{channel:?}: {}", rgb[channel]);
+///     }
+/// }
+/// ```
+///
+/// Pass `generate_mask` to `#[ters]` on a struct of up to 8 `bool` fields to generate `to_u8` and `from_u8`, packing the fields into bits in declaration order, least significant bit first. Fields removed by `#[cfg]` keep their bits, which are then left unused.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(generate_mask)]
+/// struct Flags {
+///     #[get(copy)]
+///     read: bool,
+///     #[get(copy)]
+///     write: bool,
+///     #[get(copy)]
+///     exec: bool,
+/// }
+///
+/// fn generate_mask() {
+///     let flags = Flags::from_u8(0b101);
+///     assert!(flags.read() && !flags.write() && flags.exec());
+///     assert_eq!(flags.to_u8(), 0b101);
+/// }
+/// ```
+///
+/// Pass `try_from_int` to `#[ters]` on an enum whose variants have no fields to implement `TryFrom<u32>`, mapping integers to the variants with those discriminants. Integers which are not a discriminant are rejected with a generated `{Enum}TryFromIntError` holding them. Use `try_from_int = u8` and so on for another integer type.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(try_from_int)]
+/// #[derive(Debug, PartialEq)]
+/// enum Opcode {
+///     Nop,
+///     Load = 4,
+///     Store,
+/// }
+///
+/// fn try_from_int() {
+///     assert_eq!(Opcode::try_from(5), Ok(Opcode::Store));
+///     assert_eq!(Opcode::try_from(1), Err(OpcodeTryFromIntError(1)));
+/// }
+/// ```
+///
+/// The types generated for `into_fields` and `generate_index` name the fields, so on a `#[non_exhaustive]` struct they are `#[non_exhaustive]` too. Adding a field then cannot break code downstream which matches on them exhaustively.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(into_fields)]
+/// #[non_exhaustive]
+/// pub struct Config {
+///     #[get]
+///     retries: u32,
+/// }
+///
+/// fn non_exhaustive_fields(config: Config) {
+///     // other crates must write `ConfigFields { retries, .. }`
+///     let ConfigFields { retries, .. } = config.into();
+///     println!("{retries}");
+/// }
+/// ```
+///
+/// Attributes on the struct are left in place for other macros, so `#[ters]` composes with derives such as `thiserror::Error`, whose `#[error]` attribute is preserved.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters]
+/// #[derive(Debug, thiserror::Error)]
+/// #[error("unexpected input at {position}")]
+/// struct ParseError {
+///     #[get]
+///     input: String,
+///     #[get(copy)]
+///     position: usize,
+/// }
+///
+/// fn thiserror(e: &ParseError) {
+///     println!("{e} in {}", e.input());
+/// }
+/// ```
+///
+/// Pass `generate_update_fn` to `#[ters]` to generate `update`, which copies the fields which have setters from another instance by cloning them. The other fields are left alone.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(generate_update_fn)]
+/// struct Profile {
+///     #[set]
+///     name: String,
+///     #[set]
+///     age: u8,
+///     id: u32,
+/// }
+///
+/// fn generate_update_fn(profile: &mut Profile, edited: &Profile) {
+///     // `id` is kept
+///     profile.update(edited);
+/// }
+/// ```
+///
+/// Pass `no_docs` to `#[ters]` to leave out the `Getter for` and `Setter for` headings of the accessors' documentation. Documentation on the fields is still forwarded.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(no_docs)]
+/// struct Foo {
+///     /// The number of retries, documented on the getter as is.
+///     #[get]
+///     retries: u32,
+/// }
+///
+/// fn no_docs(foo: &Foo) {
+///     println!("{}", foo.retries());
+/// }
+/// ```
+///
+/// Pass `generate_snapshot` to `#[ters]` to generate a `{Struct}Snapshot` struct holding clones of the fields which have getters, along with a `snapshot` method taking one. Snapshots implement `Debug`, `Clone`, and `PartialEq`, so state can be captured and compared later.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(generate_snapshot)]
+/// struct Inventory {
+///     #[get]
+///     items: Vec<String>,
+///     #[get]
+///     #[set]
+///     capacity: usize,
+/// }
+///
+/// fn generate_snapshot(inventory: &mut Inventory) {
+///     let before = inventory.snapshot();
+///     inventory.set_capacity(8);
+///
+///     assert_ne!(inventory.snapshot(), before);
+/// }
+/// ```
+///
+/// Pass `generate_diff` to `#[ters]` to generate a `{Struct}Diff` struct with an `Option` for each field which has a getter, along with a `diff` method. Each `Option` holds a clone of the other value if the field differs, and `None` otherwise.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(generate_diff)]
+/// struct Config {
+///     #[get]
+///     host: String,
+///     #[get]
+///     port: u16,
+/// }
+///
+/// fn generate_diff(old: &Config, new: &Config) {
+///     let diff = old.diff(new);
+///
+///     if let Some(port) = diff.port {
+///         println!("port changed to {port}");
+///     }
+/// }
+/// ```
+///
+/// Pass `generate_merge` alongside `generate_diff` to generate `merge`, which applies a `{Struct}Diff`. Each field which is `Some` in the diff is stored through the field's setter. Fields which are `None`, and fields without a setter taking the value, such as `read_only` ones, are left unchanged.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(generate_diff, generate_merge)]
+/// struct Config {
+///     #[get]
+///     host: String,
+///     #[get]
+///     #[set]
+///     port: u16,
+/// }
+///
+/// fn generate_merge(config: &mut Config, edited: &Config) {
+///     let patch = config.diff(edited);
+///     config.merge(patch);
+/// }
+/// ```
+///
+/// With the `std` feature enabled, pass `generate_setters_from_env` to `#[ters]` to generate `set_from_env`. It parses each field which has a setter from the environment variable named after the struct and field in `SCREAMING_SNAKE_CASE`, using `FromStr`, and stores it through the setter. Fields whose variables are not set are left unchanged. Failures are reported with a generated `{Struct}EnvError` naming the variable.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(generate_setters_from_env)]
+/// struct ServerConfig {
+///     #[set]
+///     host: String,
+///     #[set]
+///     port: u16,
+/// }
+///
+/// fn generate_setters_from_env(config: &mut ServerConfig) -> Result<(), ServerConfigEnvError> {
+///     // reads `SERVER_CONFIG_HOST` and `SERVER_CONFIG_PORT`
+///     config.set_from_env()
+/// }
+/// ```
+///
+/// With the `serde` feature enabled, pass `generate_serde_accessor_trait` to `#[ters]` to generate a `{Struct}SerdeAccessors` trait, implemented for the struct. It has a `_json` method for each field which has a getter, serializing the field with `serde_json::to_value`. The feature pulls in `serde` and `serde_json` for the generated code, and the fields' types must implement `Serialize`.
+/// ```ignore
+/// use ters::ters;
+///
+/// #[ters(generate_serde_accessor_trait)]
+/// struct User {
+///     #[get]
+///     name: String,
+///     #[get]
+///     roles: Vec<String>,
+/// }
+///
+/// fn generate_serde_accessor_trait(user: &User) {
+///     println!("{}", user.roles_json().unwrap());
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn ters(args: TokenStream, tokens: TokenStream) -> TokenStream {
+    let mut errors = Errors::default();
+
+    let mut parsed_args = Args::default();
+    errors.ok(syn::meta::parser(|meta| parsed_args.parse(meta)).parse(args));
+
+    let item = parse_macro_input!(tokens as Item);
+
+    let tokens = match item {
+        Item::Struct(item) => ters_inner(parsed_args, item, &mut errors),
+        Item::Enum(item) if parsed_args.try_from_int.is_some() => {
+            let (span, ty) = parsed_args.try_from_int.as_ref().unwrap();
+            let generated = errors.ok(try_from_int::expand(&item, ty, *span));
+
+            quote! {
+                #item
+                #generated
+            }
+        }
+        item if parsed_args.passthrough => item.into_token_stream(),
+        item => {
+            errors.push(unsupported_item(&item));
+            item.into_token_stream()
+        }
+    };
+
+    quote! {
+        #tokens
+        #errors
+    }
+    .into()
+}
+
+/// An error naming the kind of item `#[ters]` was applied to, other than a struct.
+fn unsupported_item(item: &Item) -> syn::Error {
+    let (span, found) = match item {
+        Item::Enum(item) => {
+            let (enum_token, ident) = (&item.enum_token, &item.ident);
+            (quote! { #enum_token #ident }, "an enum")
+        }
+        Item::Fn(item) => {
+            let (fn_token, ident) = (&item.sig.fn_token, &item.sig.ident);
+            (quote! { #fn_token #ident }, "a function")
+        }
+        Item::Impl(item) => {
+            let (impl_token, self_ty) = (&item.impl_token, &item.self_ty);
+            (quote! { #impl_token #self_ty }, "an impl block")
+        }
+        Item::Trait(item) => {
+            let (trait_token, ident) = (&item.trait_token, &item.ident);
+            (quote! { #trait_token #ident }, "a trait")
+        }
+        Item::Union(item) => {
+            let (union_token, ident) = (&item.union_token, &item.ident);
+            (quote! { #union_token #ident }, "a union")
+        }
+        Item::Type(item) => {
+            let (type_token, ident) = (&item.type_token, &item.ident);
+            (quote! { #type_token #ident }, "a type alias")
+        }
+        Item::Mod(item) => {
+            let (mod_token, ident) = (&item.mod_token, &item.ident);
+            (quote! { #mod_token #ident }, "a module")
+        }
+        other => (quote! { #other }, "an unsupported item"),
+    };
+
+    // enums are supported, just not without the option
+    let message = match item {
+        Item::Enum(_) => format!("`#[ters]` on {found} requires `try_from_int`"),
+        _ => format!("`#[ters]` can only be applied to structs, and to enums with `try_from_int`; found {found}"),
+    };
+
+    syn::Error::new_spanned(span, message)
+}
+
+/// Derive getters for a struct, as an alternative to `#[ters]`.
+///
+/// Fields are annotated with `#[get]` just as with `#[ters]`, but struct-level
+/// `#[ters(...)]` options are not available.
+///
+/// ```ignore
+/// use ters::Getters;
+///
+/// #[derive(Getters)]
+/// struct Foo {
+///     #[get]
+///     a: i32,
+/// }
+/// ```
+#[proc_macro_derive(Getters, attributes(get))]
+pub fn derive_getters(tokens: TokenStream) -> TokenStream {
+    derive("Getters", tokens)
+}
+
+/// Derive setters for a struct, as an alternative to `#[ters]`.
+///
+/// Fields are annotated with `#[set]` just as with `#[ters]`, but struct-level
+/// `#[ters(...)]` options are not available.
+///
+/// ```ignore
+/// use ters::Setters;
+///
+/// #[derive(Setters)]
+/// struct Foo {
+///     #[set]
+///     a: i32,
+/// }
+/// ```
+#[proc_macro_derive(Setters, attributes(set))]
+pub fn derive_setters(tokens: TokenStream) -> TokenStream {
+    derive("Setters", tokens)
+}
+
+fn derive(name: &str, tokens: TokenStream) -> TokenStream {
+    let mut errors = Errors::default();
+
+    let item = parse_macro_input!(tokens as Item);
+
+    let tokens = match item {
+        Item::Struct(item) => derive_inner(name, item, &mut errors),
+        item => {
+            errors.push(syn::Error::new_spanned(
+                unsupported_item(&item).into_compile_error(),
+                format!("`#[derive({name})]` can only be applied to structs"),
+            ));
+            proc_macro2::TokenStream::new()
+        }
+    };
+
+    quote! {
+        #tokens
+        #errors
+    }
+    .into()
+}
+
+/// Generate the accessors of one kind for `item`, which derive macros must not re-emit.
+fn derive_inner(name: &str, mut item: ItemStruct, errors: &mut Errors) -> proc_macro2::TokenStream {
+    // the other derive, if any, handles the other kind of accessor,
+    // and any `#[ters]` attribute expands on its own
+    let other = if name == "Getters" { "set" } else { "get" };
+
+    item.attrs.retain(|attr| !attr.path().is_ident("ters"));
+
+    for field in &mut item.fields {
+        // the `#[get]` declaring a field read-only still forbids its setters
+        if let Some(read_only) = field
+            .attrs
+            .iter()
+            .find(|attr| other == "get" && is_read_only(attr))
+        {
+            field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("set"))
+                .for_each(|attr| errors.push(read_only_error(attr, read_only)));
+            field.attrs.retain(|attr| !attr.path().is_ident("set"));
+        }
+
+        field.attrs.retain(|attr| !attr.path().is_ident(other));
+    }
+
+    expand_struct(Args::default(), item, Some(name), errors).1
+}
+
+/// Generate the accessors for `item`, emitting the struct itself along with them.
+///
+/// This cannot be exported for other proc macros to call, since proc-macro crates may
+/// only export macros; they can emit `#[ters::ters]` on the structs they generate instead.
+fn ters_inner(args: Args, item: ItemStruct, errors: &mut Errors) -> proc_macro2::TokenStream {
+    let (item, generated) = expand_struct(args, item, None, errors);
+
+    quote! {
+        #item
+        #generated
+    }
+}
+
+/// Generate the accessors for `item`, returning the struct to emit along with them.
+///
+/// Errors are accumulated into `errors` rather than aborting, so all of them are
+/// reported at once. `derive` names the derive macro expanding the struct, if any.
+fn expand_struct(
+    mut args: Args,
+    mut item: ItemStruct,
+    derive: Option<&str>,
+    errors: &mut Errors,
+) -> (ItemStruct, proc_macro2::TokenStream) {
+    // further `#[ters]` applications are merged into this one
+    // so all accessors are generated into a single impl block
+    item.attrs.retain(|attr| {
+        if attr
+            .path()
+            .segments
+            .last()
+            .is_none_or(|segment| segment.ident != "ters")
+        {
+            return true;
+        }
+
+        if let Meta::List(_) = attr.meta {
+            errors.ok(attr.parse_nested_meta(|meta| args.parse(meta)));
+        }
+
+        false
+    });
+
+    // only strip the field attributes which would otherwise be consumed,
+    // leaving the struct as written
+    if args.passthrough {
+        for field in &mut item.fields {
+            field.attrs.retain(|attr| {
+                !["get", "set", "include_in_constructor"]
+                    .iter()
+                    .any(|name| attr.path().is_ident(name))
+            });
+        }
+
+        return (item, proc_macro2::TokenStream::new());
+    }
+
+    item.attrs.retain(|attr| {
+        if !attr.path().is_ident("get") && !attr.path().is_ident("set") {
+            return true;
+        }
+
+        let name = attr.path().get_ident().unwrap();
+
+        errors.push(syn::Error::new_spanned(
+            attr,
+            format!("`#[{name}]` is a field attribute; place it on the fields to generate accessors for, or use `#[ters({name})]` to generate them for every field"),
+        ));
+
+        false
+    });
+
+    // fields listed in `#[ters(get = [...], set = [...])]` are treated as annotated
+    for (name, listed) in [("get", &args.get), ("set", &args.set)] {
+        for listed in listed {
+            let Some(field) = item
+                .fields
+                .iter_mut()
+                .find(|field| field.ident.as_ref() == Some(listed))
+            else {
+                errors.push(syn::Error::new_spanned(
+                    listed,
+                    format!("`{name}` lists `{listed}`, but there is no field named `{listed}`"),
+                ));
+                continue;
+            };
+
+            let path = Ident::new(name, listed.span());
+            field
+                .attrs
+                .push(parse_quote_spanned! {listed.span()=> #[#path] });
+        }
+    }
+
+    for excluded in &args.exclude {
+        if !item
+            .fields
+            .iter()
+            .any(|field| field.ident.as_ref() == Some(excluded))
+        {
+            errors.push(syn::Error::new_spanned(
+                excluded,
+                format!("`exclude` lists `{excluded}`, but there is no field named `{excluded}`"),
+            ));
+        }
+    }
+
+    if let (None, None, Some(excluded)) = (args.get_all, args.set_all, args.exclude.first()) {
+        errors.push(syn::Error::new_spanned(
+            excluded,
+            "`exclude` only applies to `get` and `set` without field lists; add either as well",
+        ));
+    }
+
+    if let (None, Some(span)) = (args.generate_diff, args.generate_merge) {
+        errors.push(syn::Error::new(
+            span,
+            "`generate_merge` applies the diffs of `generate_diff`; add it as well",
+        ));
+    }
+
+    if let Some((span, _)) = args.try_from_int {
+        errors.push(syn::Error::new(
+            span,
+            "`try_from_int` only applies to enums; remove it from the struct",
+        ));
+    }
+
+    if let (None, None, Some(span)) = (args.get_all, args.set_all, args.phantom_skip) {
+        errors.push(syn::Error::new(
+            span,
+            "`phantom_skip` only applies to `get` and `set` without field lists; add either as well",
+        ));
+    }
+
+    // `#[ters(get)]` and `#[ters(set)]` fill in the fields not annotated already
+    for (name, span) in [("get", args.get_all), ("set", args.set_all)] {
+        let Some(span) = span else {
+            continue;
+        };
+
+        // tuple struct fields need a `name` each, so none would be filled in
+        if let syn::Fields::Unnamed(_) = item.fields {
+            errors.push(syn::Error::new(
+                span,
+                format!("`{name}` without a field list requires a struct with named fields; annotate the tuple struct fields with a `name` instead"),
+            ));
+            continue;
+        }
+
+        for field in &mut item.fields {
+            if field
+                .ident
+                .as_ref()
+                .is_none_or(|ident| args.exclude.contains(ident))
+                || (args.phantom_skip.is_some() && generic_arg(&field.ty, "PhantomData").is_some())
+                || field.attrs.iter().any(|attr| attr.path().is_ident(name))
+                // read-only fields are not settable, so are left out rather than rejected
+                || name == "set" && field.attrs.iter().any(is_read_only)
+            {
+                continue;
+            }
+
+            let path = Ident::new(name, span);
+            field.attrs.push(parse_quote_spanned! {span=> #[#path] });
+        }
+    }
+
+    let mut fields = item
+        .fields
+        .iter_mut()
+        .enumerate()
+        .map(|(index, field)| Field::parse(field, index, &args, errors))
+        .collect::<Vec<_>>();
+
+    // getters zipping a field with the next one need to know about it
+    for i in 0..fields.len() {
+        let next = fields
+            .get(i + 1)
+            .map(|next| (next.ident.clone(), next.member.clone(), next.ty.clone()));
+
+        fields[i]
+            .getters
+            .retain_mut(|(_, getter)| errors.ok(getter.zip_with(next.as_ref())).is_some());
+    }
+
+    let mut names = Names::default();
+    let mut accessors = Vec::new();
+
+    // claimed first so accessors named after them are reported instead of redefining them
+    let options = [
+        (args.constructor, "constructor", &["new"][..]),
+        (
+            args.generate_builder_fn,
+            "generate_builder_fn",
+            &["builder"],
+        ),
+        (args.generate_mask, "generate_mask", &["to_u8", "from_u8"]),
+        (args.generate_update_fn, "generate_update_fn", &["update"]),
+        (args.generate_snapshot, "generate_snapshot", &["snapshot"]),
+        (args.generate_diff, "generate_diff", &["diff"]),
+        (args.generate_merge, "generate_merge", &["merge"]),
+        (
+            args.generate_setters_from_env,
+            "generate_setters_from_env",
+            &["set_from_env"],
+        ),
+    ];
+
+    for (span, option, methods) in options {
+        let Some(span) = span else {
+            continue;
+        };
+
+        for method in methods {
+            names.claim_option(method, option, span);
+        }
+    }
+
+    // values of a `#[must_use]` type are presumably worth using, so getters are too
+    let must_use = item
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("must_use"));
+
+    // the accessibility of a method is bounded by that of its type anyway,
+    // so this only catches declarations which read as wider than they are
+    if args.visibility_check {
+        let accessors = fields.iter().flat_map(|field| {
+            field
+                .getters
+                .iter()
+                .map(|(attr, getter)| (attr, getter.ident(&field.ident), getter.vis()))
+                .chain(
+                    field
+                        .setters
+                        .iter()
+                        .map(|(attr, setter)| (attr, setter.ident(&field.ident), setter.vis())),
+                )
+        });
+
+        for (attr, method, vis) in accessors {
+            if visibility::is_wider(&vis, &item.vis) {
+                errors.push(syn::Error::new_spanned(
+                    attr,
+                    format!(
+                        "`{method}` is declared `{}` but `{}` is {}, which limits its accessibility",
+                        visibility::describe(&vis),
+                        item.ident,
+                        match &item.vis {
+                            syn::Visibility::Inherited => "private".into(),
+                            vis => format!("only `{}`", visibility::describe(vis)),
+                        }
+                    ),
+                ));
+            }
+        }
+    }
+
+    // methods renamed to another case are not snake case, by request
+    let case_lint = args
+        .rename_all
+        .filter(|case| *case != Case::Snake)
+        .map(|_| quote! { #[allow(non_snake_case)] });
+
+    for field in &fields {
+        for (attr, getter) in &field.getters {
+            let method = getter.ident(&field.ident);
+
+            if errors
+                .ok(names.claim(method.clone(), &field.ident, attr))
+                .is_some()
+            {
+                let accessor = match getter.fn_ptr() {
+                    Some(span) => ffi::getter(&item, field, &method, &getter.vis(), span)
+                        .map(|constant| field.constant("Getter", constant)),
+                    None => getter.expand(field).map(|method| {
+                        field.accessor("Getter", quote! { #must_use #case_lint #method })
+                    }),
+                };
+
+                accessors.extend(errors.ok(accessor));
+            }
+        }
+
+        for (attr, setter) in &field.setters {
+            let method = setter.ident(&field.ident);
+
+            if setter.is_bare()
+                && field
+                    .getters
+                    .iter()
+                    .any(|(_, getter)| getter.ident(&field.ident) == method)
+            {
+                errors.push(syn::Error::new_spanned(
+                    attr,
+                    format!("empty setter prefix would produce method name `{method}` which conflicts with getter"),
+                ));
+                continue;
+            }
+
+            if errors
+                .ok(names.claim(method.clone(), &field.ident, attr))
+                .is_some()
+            {
+                let accessor = match setter.fn_ptr() {
+                    Some(span) => ffi::setter(&item, field, &method, &setter.vis(), span)
+                        .map(|constant| field.constant("Setter", constant)),
+                    None => setter
+                        .expand(field)
+                        .map(|method| field.accessor("Setter", quote! { #case_lint #method })),
+                };
+
+                accessors.extend(errors.ok(accessor));
+            }
+        }
+    }
+
+    // inherent methods take precedence over trait methods, so `foo.clone()`
+    // would silently call the accessor instead
+    if args.conflict_check {
+        for (method, attr, shadowed) in names.shadowing() {
+            errors.push(syn::Error::new_spanned(
+                attr,
+                format!("`{method}` shadows `{shadowed}`, so method calls will resolve to the accessor instead"),
+            ));
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+    let ident = &item.ident;
+
+    let constructor = constructor::expand(ident, &args, &fields, errors);
+    let builder_fn = constructor::builder_fn(ident, &ty_generics, &args);
+    let mask = args
+        .generate_mask
+        .and_then(|span| errors.ok(mask::expand(&item, &fields, &args, span)));
+    let update = args
+        .generate_update_fn
+        .and_then(|span| errors.ok(update::expand(&fields, &args, span)));
+    let (snapshot_fn, snapshot) = args
+        .generate_snapshot
+        .and_then(|span| errors.ok(snapshot::expand(&item, &fields, &args, span)))
+        .unzip();
+    let (env_fn, env_error) = args
+        .generate_setters_from_env
+        .and_then(|span| errors.ok(env::expand(&item, &fields, &args, span)))
+        .unzip();
+    let (diff_fn, diff) = args
+        .generate_diff
+        .and_then(|span| errors.ok(diff::expand(&item, &fields, &args, span)))
+        .unzip();
+
+    let impl_ = (constructor.is_some()
+        || builder_fn.is_some()
+        || mask.is_some()
+        || update.is_some()
+        || snapshot_fn.is_some()
+        || diff_fn.is_some()
+        || env_fn.is_some()
+        || !accessors.is_empty())
+    .then_some(quote! {
+        #[automatically_derived]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #constructor
+            #builder_fn
+            #mask
+            #update
+            #snapshot_fn
+            #diff_fn
+            #env_fn
+            #(
+                #accessors
+            )*
+        }
+    });
+
+    let into_fields = args
+        .into_fields
+        .and_then(|span| errors.ok(into_fields::expand(&item, &fields, span)));
+
+    let display = args.impl_display.then(|| display::expand(&item, &fields));
+    let serde_accessors = args
+        .generate_serde_accessor_trait
+        .then(|| serde_accessors::expand(&item, &fields));
+    let index = args
+        .generate_index
+        .and_then(|span| errors.ok(index::expand(&item, &fields, span)));
+    let from_tuple = args
+        .impl_from_tuple
+        .and_then(|span| errors.ok(from_tuple::expand(&item, span)));
+
+    if let Some(dir) = std::env::var_os(dump::VAR) {
+        errors.ok(dump::write(
+            dir.as_ref(),
+            ident,
+            derive,
+            names.methods(),
+            impl_.as_ref(),
+        ));
+    }
+
+    // `proc_macro::Diagnostic` is unstable, so the listing is surfaced
+    // as a deprecation warning on an item used right away
+    let info = args.info.map(|span| {
+        let methods = names
+            .methods()
+            .map(|method| format!("`{method}`"))
+            .collect::<Vec<_>>();
+        let note = if methods.is_empty() {
+            format!("`#[ters]` generates no methods for `{ident}`")
+        } else {
+            format!(
+                "`#[ters]` generates these methods for `{ident}`: {}",
+                methods.join(", ")
+            )
+        };
+
+        quote_spanned! {span=>
+            const _: () = {
+                #[deprecated(note = #note)]
+                struct TersInfo;
+
+                let _ = TersInfo;
+            };
+        }
+    });
+
+    let generated = quote! {
+        #impl_
+        #into_fields
+        #snapshot
+        #diff
+        #env_error
+        #index
+        #from_tuple
+        #display
+        #serde_accessors
+        #info
+    };
+
+    (item, generated)
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::quote;
+    use syn::parse_quote;
+
+    use crate::{derive_inner, errors::Errors, ters_inner, unsupported_item, Args};
+
+    /// Expand `input`, asserting no errors are reported.
+    fn expand(args: Args, input: syn::ItemStruct) -> proc_macro2::TokenStream {
+        let mut errors = Errors::default();
+        let out = ters_inner(args, input, &mut errors);
+
+        if let Err(e) = errors.finish() {
+            panic!("unexpected error: {e}");
+        }
+
+        out
+    }
+
+    /// Expand `input`, returning the reported errors.
+    fn expand_err(args: Args, input: syn::ItemStruct) -> syn::Error {
+        let mut errors = Errors::default();
+        ters_inner(args, input, &mut errors);
+
+        errors.finish().expect_err("expected an error")
+    }
+
+    #[test]
+    fn docs() {
+        let input = parse_quote! {
+            struct Foo {
+                /// Baz.
+                #[get]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                /// Baz.
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                /// Baz.
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn cfgs() {
+        let input = parse_quote! {
+            struct Foo {
+                /// Baz.
+                #[cfg(feature = "a")]
+                #[cfg(not(feature = "b"))]
+                #[get]
+                #[set]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                /// Baz.
+                #[cfg(feature = "a")]
+                #[cfg(not(feature = "b"))]
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[cfg(feature = "a")]
+                #[cfg(not(feature = "b"))]
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                /// Baz.
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+                #[cfg(feature = "a")]
+                #[cfg(not(feature = "b"))]
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                /// Baz.
+                #[inline]
+                pub fn set_bar(&mut self, value: u8) {
+                    self.bar = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn associated_types() {
+        let input = parse_quote! {
+            struct Foo<I: Iterator, T: Assoc> {
+                #[get]
+                a: <I as Iterator>::Item,
+                #[get]
+                b: T::Assoc,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo<I: Iterator, T: Assoc> {
+                a: <I as Iterator>::Item,
+                b: T::Assoc,
+            }
+
+            #[automatically_derived]
+
+            impl<I: Iterator, T: Assoc> Foo<I, T> {
+                #[doc = "Getter for `"]
+                #[doc = "a"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn a(&self) -> &<I as Iterator>::Item {
+                    &self.a
+                }
+                #[doc = "Getter for `"]
+                #[doc = "b"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn b(&self) -> &T::Assoc {
+                    &self.b
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn cfg_attrs() {
+        let input = parse_quote! {
+            struct Foo {
+                #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+                #[cfg_attr(feature = "x", deprecated, allow(unused))]
+                #[cfg_attr(feature = "y", allow(unused))]
+                #[get]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+                #[cfg_attr(feature = "x", deprecated, allow(unused))]
+                #[cfg_attr(feature = "y", allow(unused))]
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[cfg_attr(docsrs, doc(cfg(feature = "tls")))]
+                #[cfg_attr(feature = "x", deprecated, allow(deprecated))]
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn normalize() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set(normalize = str::trim)]
+                bar: &'static str,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: &'static str,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_bar(&mut self, value: &'static str) {
+                    self.bar = str::trim(value);
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn deprecated() {
+        let input = parse_quote! {
+            struct Foo {
+                #[deprecated(since = "0.1.0", note = "use `baz`")]
+                #[get]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                #[deprecated(since = "0.1.0", note = "use `baz`")]
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[deprecated(since = "0.1.0", note = "use `baz`")]
+                #[allow(deprecated)]
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn expect_lints() {
+        let input = parse_quote! {
+            #[expect(dead_code)]
+            struct Foo {
+                #[get]
+                a: i32,
+            }
+        };
+
+        // the struct's expectation stays on the struct, not the generated impl
+        let expected = quote! {
+            #[expect(dead_code)]
+            struct Foo {
+                a: i32,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "a"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn a(&self) -> &i32 {
+                    &self.a
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn propagate_lints() {
+        let input = parse_quote! {
+            struct Foo {
+                #[allow(clippy::struct_field_names)]
+                #[expect(dead_code)]
+                #[get]
+                foo_bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                #[allow(clippy::struct_field_names)]
+                #[expect(dead_code)]
+                foo_bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[allow(clippy::struct_field_names)]
+                #[allow(dead_code)]
+                #[doc = "Getter for `"]
+                #[doc = "foo_bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn foo_bar(&self) -> &u8 {
+                    &self.foo_bar
+                }
+            }
+        };
+
+        let args = Args {
+            propagate_lints: true,
+            ..Default::default()
+        };
+        let out = expand(args, input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn try_from_int_on_struct() {
+        let input = parse_quote! {
+            #[ters(try_from_int = u8)]
+            struct Foo {
+                #[get]
+                bar: u8,
+            }
+        };
+
+        let e = expand_err(Args::default(), input);
+
+        assert_eq!(
+            e.to_string(),
+            "`try_from_int` only applies to enums; remove it from the struct"
+        );
+    }
+
+    #[test]
+    fn non_struct_items() {
+        let cases: [(syn::Item, &str); 4] = [
+            (
+                parse_quote! { enum Foo { A, B } },
+                "`#[ters]` on an enum requires `try_from_int`",
+            ),
+            (
+                parse_quote! { fn foo() {} },
+                "`#[ters]` can only be applied to structs, and to enums with `try_from_int`; found a function",
+            ),
+            (
+                parse_quote! { impl Foo {} },
+                "`#[ters]` can only be applied to structs, and to enums with `try_from_int`; found an impl block",
+            ),
+            (
+                parse_quote! { trait Foo {} },
+                "`#[ters]` can only be applied to structs, and to enums with `try_from_int`; found a trait",
+            ),
+        ];
+
+        for (item, message) in cases {
+            assert_eq!(unsupported_item(&item).to_string(), message);
+        }
+    }
+
+    #[test]
+    fn format_with() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get]
+                #[get(format_with = fmt_bar)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar_display(&self) -> impl ::core::fmt::Display + '_ {
+                    fmt_bar(&self.bar)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn struct_level_accessor_attrs() {
+        let input = parse_quote! {
+            #[get]
+            struct Foo {
+                bar: u8,
+            }
+        };
+
+        let e = expand_err(Args::default(), input);
+
+        assert_eq!(
+            e.to_string(),
+            "`#[get]` is a field attribute; place it on the fields to generate accessors for, or use `#[ters(get)]` to generate them for every field"
+        );
+    }
+
+    #[test]
+    fn conflicting_accessor_attrs() {
+        let cases: [syn::ItemStruct; 3] = [
+            parse_quote! {
+                struct Foo {
+                    #[get]
+                    #[get]
+                    bar: u8,
+                }
+            },
+            parse_quote! {
+                struct Foo {
+                    #[get(format_with = a)]
+                    #[get]
+                    #[get(format_with = b)]
+                    bar: u8,
+                }
+            },
+            parse_quote! {
+                struct Foo {
+                    #[set]
+                    #[set(normalize = a)]
+                    bar: u8,
+                }
+            },
+        ];
+
+        for input in cases {
+            let e = expand_err(Args::default(), input);
+
+            assert_eq!(e.into_iter().count(), 2);
+        }
+    }
+
+    #[test]
+    fn merge_applications() {
+        let input = parse_quote! {
+            #[ters(propagate_lints)]
+            struct Foo {
+                #[get]
+                #[allow(unused)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                #[allow(unused)]
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[allow(unused)]
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn derive_read_only() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(read_only)]
+                #[set]
+                bar: u8,
+            }
+        };
+
+        let mut errors = Errors::default();
+        let out = derive_inner("Setters", input, &mut errors);
+
+        assert!(out.is_empty());
+        assert_eq!(
+            errors
+                .finish()
+                .expect_err("expected an error")
+                .into_iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>(),
+            [
+                "setters cannot be generated for a field declared `#[get(read_only)]`",
+                "field declared read-only here"
+            ]
+        );
+    }
+
+    #[test]
+    fn method_collisions() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set]
+                bar: u8,
+                #[get]
+                set_bar: u8,
+            }
+        };
+
+        let e = expand_err(Args::default(), input);
+
+        assert_eq!(
+            e.to_string(),
+            "method `set_bar` generated for field `set_bar` collides with a method generated for field `bar`"
+        );
+        assert_eq!(e.into_iter().count(), 2);
+    }
+
+    #[test]
+    fn option_method_collisions() {
+        let cases: [(syn::ItemStruct, &str); 8] = [
+            (
+                parse_quote! {
+                    #[ters(constructor)]
+                    struct Foo {
+                        #[get]
+                        new: u8,
+                    }
+                },
+                "method `new` generated for field `new` collides with the method generated by `constructor`",
+            ),
+            (
+                parse_quote! {
+                    #[ters(generate_builder_fn)]
+                    struct Foo {
+                        #[get]
+                        builder: u8,
+                    }
+                },
+                "method `builder` generated for field `builder` collides with the method generated by `generate_builder_fn`",
+            ),
+            (
+                parse_quote! {
+                    #[ters(generate_mask)]
+                    struct Foo {
+                        #[get]
+                        to_u8: bool,
+                    }
+                },
+                "method `to_u8` generated for field `to_u8` collides with the method generated by `generate_mask`",
+            ),
+            (
+                parse_quote! {
+                    #[ters(generate_mask)]
+                    struct Foo {
+                        #[get]
+                        from_u8: bool,
+                    }
+                },
+                "method `from_u8` generated for field `from_u8` collides with the method generated by `generate_mask`",
+            ),
+            (
+                parse_quote! {
+                    #[ters(generate_update_fn)]
+                    struct Foo {
+                        #[get]
+                        #[set]
+                        update: u8,
+                    }
+                },
+                "method `update` generated for field `update` collides with the method generated by `generate_update_fn`",
+            ),
+            (
+                parse_quote! {
+                    #[ters(generate_snapshot)]
+                    struct Foo {
+                        #[get]
+                        snapshot: u8,
+                    }
+                },
+                "method `snapshot` generated for field `snapshot` collides with the method generated by `generate_snapshot`",
+            ),
+            (
+                parse_quote! {
+                    #[ters(generate_diff)]
+                    struct Foo {
+                        #[get]
+                        diff: u8,
+                    }
+                },
+                "method `diff` generated for field `diff` collides with the method generated by `generate_diff`",
+            ),
+            (
+                parse_quote! {
+                    #[ters(generate_diff, generate_merge)]
+                    struct Foo {
+                        #[get]
+                        #[set]
+                        merge: u8,
+                    }
+                },
+                "method `merge` generated for field `merge` collides with the method generated by `generate_merge`",
+            ),
+        ];
+
+        for (input, message) in cases {
+            let e = expand_err(Args::default(), input);
+
+            assert_eq!(e.to_string(), message);
+            assert_eq!(e.into_iter().count(), 2);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn set_from_env_collision() {
+        let input = parse_quote! {
+            #[ters(generate_setters_from_env)]
+            struct Foo {
+                #[set]
+                from_env: u8,
+            }
+        };
+
+        let e = expand_err(Args::default(), input);
+
+        assert_eq!(
+            e.to_string(),
+            "method `set_from_env` generated for field `from_env` collides with the method generated by `generate_setters_from_env`"
+        );
+        assert_eq!(e.into_iter().count(), 2);
+    }
+
+    #[test]
+    fn on_read() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(on_read = log_access)]
+                bar: u8,
+                #[get(on_read = |name| record(name))]
+                baz: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+                baz: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    (log_access)();
+                    &self.bar
+                }
+                #[doc = "Getter for `"]
+                #[doc = "baz"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn baz(&self) -> &u8 {
+                    (|name| record(name))("baz");
+                    &self.baz
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn rename() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(name = "r#type")]
+                #[set(name = "with_bar")]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn r#type(&self) -> &u8 {
+                    &self.bar
+                }
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn with_bar(&mut self, value: u8) {
+                    self.bar = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn invalid_rename() {
+        let cases: [(syn::ItemStruct, &str); 3] = [
+            (
+                parse_quote! {
+                    struct Foo {
+                        #[get(name = "my getter")]
+                        bar: u8,
+                    }
+                },
+                "`my getter` is not a valid identifier",
+            ),
+            (
+                parse_quote! {
+                    struct Foo {
+                        #[set(name = "fn")]
+                        bar: u8,
+                    }
+                },
+                "`fn` is a keyword; use a raw identifier (`r#fn`) instead",
+            ),
+            (
+                parse_quote! {
+                    struct Foo {
+                        #[get(name = "self")]
+                        bar: u8,
+                    }
+                },
+                "`self` cannot be used as a method name",
+            ),
+        ];
+
+        for (input, message) in cases {
+            let e = expand_err(Args::default(), input);
+
+            assert_eq!(e.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn builder() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set(builder)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                #[must_use = "this method returns Self for chaining"]
+                pub fn with_bar(mut self, value: u8) -> Self {
+                    self.bar = value;
+                    self
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn all_errors_reported() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(bogus)]
+                bar: u8,
+                #[set(name = "fn")]
+                baz: u8,
+                #[get]
+                qux: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+                baz: u8,
+                qux: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "qux"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn qux(&self) -> &u8 {
+                    &self.qux
+                }
+            }
+        };
+
+        let mut errors = Errors::default();
+        let out = ters_inner(Args::default(), input, &mut errors);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let messages = errors
+            .finish()
+            .unwrap_err()
+            .into_iter()
+            .map(|e| e.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            messages,
+            [
+                "unrecognized getter option",
+                "`fn` is a keyword; use a raw identifier (`r#fn`) instead",
+            ]
+        );
+    }
+
+    #[test]
+    fn pinned_fields() {
+        let input = parse_quote! {
+            #[pin_project]
+            struct Foo {
+                #[pin]
+                #[get]
+                bar: u8,
+                #[get]
+                baz: u8,
+            }
+        };
+
+        let expected = quote! {
+            #[pin_project]
+            struct Foo {
+                #[pin]
+                bar: u8,
+                baz: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "baz"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn baz(&self) -> &u8 {
+                    &self.baz
+                }
+            }
+        };
+
+        let mut errors = Errors::default();
+        let out = ters_inner(Args::default(), input, &mut errors);
+
+        assert_eq!(out.to_string(), expected.to_string());
+        assert_eq!(
+            errors.finish().unwrap_err().to_string(),
+            "accessors are not generated for `#[pin]` fields; use the projection provided by `pin_project` instead"
+        );
+    }
+
+    #[test]
+    fn copy_and_clone() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(copy)]
+                bar: u8,
+                #[get(clone)]
+                baz: String,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+                baz: String,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> u8 {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::marker::Copy>() {}
+                        field_must_implement::<u8>();
+                    }
+                    self.bar
+                }
+                #[doc = "Getter for `"]
+                #[doc = "baz"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn baz(&self) -> String {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::clone::Clone>() {}
+                        field_must_implement::<String>();
+                    }
+                    ::core::clone::Clone::clone(&self.baz)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn matches() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(matches = State::A | State::B { .. }, name = "is_ready")]
+                #[get(matches = State::C, name = "is_done")]
+                bar: State,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: State,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn is_ready(&self) -> bool {
+                    ::core::matches!(self.bar, State::A | State::B { .. })
+                }
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn is_done(&self) -> bool {
+                    ::core::matches!(self.bar, State::C)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[get(matches = State::A)]
+                    bar: State,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`matches` requires a `name` for the generated predicate"
+        );
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[get(copy, matches = State::A, name = "is_a")]
+                    bar: State,
+                }
+            },
+        );
+
+        assert_eq!(err.to_string(), "`matches` cannot be combined with `copy`");
+    }
+
+    #[test]
+    fn sized() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(sized)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8
+                where
+                    Self: ::core::marker::Sized
+                {
+                    &self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn info() {
+        let input = parse_quote! {
+            #[ters(info)]
+            struct Foo {
+                #[get]
+                #[set]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_bar(&mut self, value: u8) {
+                    self.bar = value;
+                }
+            }
+
+            const _: () = {
+                #[deprecated(note = "`#[ters]` generates these methods for `Foo`: `bar`, `set_bar`")]
+                struct TersInfo;
+
+                let _ = TersInfo;
+            };
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn result_accessors() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(ok)]
+                #[get(err)]
+                #[get(is_ok)]
+                bar: Result<u8, Error>,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: Result<u8, Error>,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar_ok(&self) -> ::core::option::Option<&u8> {
+                    ::core::result::Result::ok(::core::result::Result::as_ref(&self.bar))
+                }
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar_err(&self) -> ::core::option::Option<&Error> {
+                    ::core::result::Result::err(::core::result::Result::as_ref(&self.bar))
+                }
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar_is_ok(&self) -> bool {
+                    ::core::result::Result::is_ok(&self.bar)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[get(ok)]
+                    bar: Option<u8>,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`ok` requires a field of type `Result<T, E>`"
+        );
+    }
+
+    #[test]
+    fn unpack() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set(unpack)]
+                bar: (u8, u16),
+                #[set(unpack(x, y, z))]
+                baz: (f32, f32, f32),
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: (u8, u16),
+                baz: (f32, f32, f32),
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_bar(&mut self, a: u8, b: u16) {
+                    self.bar = (a, b,);
+                }
+                #[doc = "Setter for `"]
+                #[doc = "baz"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_baz(&mut self, x: f32, y: f32, z: f32) {
+                    self.baz = (x, y, z,);
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[set(unpack(x))]
+                    bar: (u8, u16),
+                    #[set(unpack)]
+                    baz: [u8; 2],
+                    #[set(unpack)]
+                    qux: (),
+                    #[set(unpack)]
+                    quux: (u8,),
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "`unpack` expects 2 parameter names for this tuple, found 1",
+                "`unpack` requires a field of tuple type",
+                "`unpack` requires a tuple of at least two elements",
+                "`unpack` requires a tuple of at least two elements",
+            ]
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_fn() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(async_fn)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub async fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn constructor() {
+        let input = parse_quote! {
+            #[ters(constructor, skip_none)]
+            struct Foo {
+                bar: u8,
+                baz: Option<u8>,
+                #[include_in_constructor]
+                qux: Option<u8>,
+                #[cfg(test)]
+                quux: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+                baz: Option<u8>,
+                qux: Option<u8>,
+                #[cfg(test)]
+                quux: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Create a new `Foo` from its fields."]
+                #[inline]
+                pub fn new(bar: u8, qux: Option<u8>, #[cfg(test)] quux: u8) -> Self {
+                    Self {
+                        bar,
+                        baz: ::core::option::Option::None,
+                        qux,
+                        #[cfg(test)]
+                        quux
+                    }
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(skip_none)]
+                struct Foo {
+                    #[include_in_constructor]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "`skip_none` only applies to the constructor; add `constructor` as well",
+                "`#[include_in_constructor]` only applies to `Option` fields skipped by `#[ters(skip_none)]`",
+            ]
+        );
+    }
+
+    #[test]
+    fn bounded() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set(bounded = "0.0..=1.0")]
+                bar: f32,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: f32,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_bar(&mut self, value: f32) {
+                    ::core::debug_assert!(
+                        (0.0..=1.0).contains(&value),
+                        "`bar` must be within `0.0..=1.0`, got {:?}",
+                        value
+                    );
+                    self.bar = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn dump() {
+        let input: syn::ItemStruct = parse_quote! {
+            #[ters(constructor)]
+            struct Foo {
+                #[get]
+                #[set]
+                bar: Option<&'static str>,
+            }
+        };
+        let ident = input.ident.clone();
+
+        let out = expand(Args::default(), input);
+        let impl_ = syn::parse2::<syn::File>(out)
+            .unwrap()
+            .items
+            .into_iter()
+            .find_map(|item| match item {
+                syn::Item::Impl(impl_) if impl_.trait_.is_none() => Some(impl_),
+                _ => None,
+            })
+            .expect("expected an inherent impl");
+        let names = impl_
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                syn::ImplItem::Fn(method) => Some(method.sig.ident.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let impl_ = quote!(#impl_);
+
+        let dir = std::env::temp_dir().join(format!("ters-dump-{}", std::process::id()));
+        crate::dump::write(&dir, &ident, None, &names, Some(&impl_)).unwrap();
+        crate::dump::write(&dir, &ident, Some("Setters"), &names[2..], Some(&impl_)).unwrap();
+
+        let listing = std::fs::read_to_string(dir.join("Foo.json")).unwrap();
+        let derived = std::fs::read_to_string(dir.join("Foo.Setters.json")).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            listing,
+            r#"{"struct":"Foo","methods":[{"name":"new","signature":"pub fn new (bar : Option < & 'static str >) -> Self"},{"name":"bar","signature":"pub fn bar (& self) -> & Option < & 'static str >"},{"name":"set_bar","signature":"pub fn set_bar (& mut self , value : Option < & 'static str >)"}]}"#
+        );
+        assert_eq!(
+            derived,
+            r#"{"struct":"Foo","methods":[{"name":"set_bar","signature":"pub fn set_bar (& mut self , value : Option < & 'static str >)"}]}"#
+        );
+    }
+
+    #[test]
+    fn vis() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(vis = "pub(super)")]
+                #[set(vis = "")]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub(super) fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                fn set_bar(&mut self, value: u8) {
+                    self.bar = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        // bare visibilities are accepted too
+        let out = expand(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[get(vis = pub(super))]
+                    #[set(vis = "")]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[get(vis = "public")]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(err.to_string(), "`public` is not a valid visibility");
+    }
+
+    #[test]
+    fn doc_aliases() {
+        let input = parse_quote! {
+            struct Foo {
+                #[doc(alias = "baz")]
+                #[doc(alias("qux", "quux"))]
+                #[doc(hidden)]
+                #[get]
+                #[set]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                #[doc(alias = "baz")]
+                #[doc(alias("qux", "quux"))]
+                #[doc(hidden)]
+                bar: u8,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[doc(hidden)]
+                #[doc(alias = "get_baz")]
+                #[doc(alias = "get_qux")]
+                #[doc(alias = "get_quux")]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[doc(hidden)]
+                #[doc(alias = "set_baz")]
+                #[doc(alias = "set_qux")]
+                #[doc(alias = "set_quux")]
+                #[inline]
+                pub fn set_bar(&mut self, value: u8) {
+                    self.bar = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn arc_ptr() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(arc_ptr)]
+                bar: Arc<u8>,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: Arc<u8>,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar_ptr(&self) -> *const u8 {
+                    ::std::sync::Arc::as_ptr(&self.bar)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[get(arc_ptr)]
+                    bar: Box<u8>,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`arc_ptr` requires a field of type `Arc<T>`"
+        );
+    }
+
+    #[test]
+    fn discriminant() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(discriminant)]
+                bar: State,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: State,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar_discriminant(&self) -> ::core::mem::Discriminant<State> {
+                    ::core::mem::discriminant(&self.bar)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn visibility_check() {
+        let args = Args {
+            visibility_check: true,
+            ..Default::default()
+        };
+
+        let input = parse_quote! {
+            pub(crate) struct Foo {
+                #[get(vis = "pub(crate)")]
+                #[set(vis = "pub(super)")]
+                bar: u8,
+            }
+        };
+
+        expand(args, input);
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(visibility_check)]
+                pub(super) struct Foo {
+                    #[get]
+                    #[set(vis = "pub(in crate::foo)")]
+                    bar: u8,
+                    #[get(vis = "pub(crate)")]
+                    baz: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "`bar` is declared `pub` but `Foo` is only `pub(super)`, which limits its accessibility",
+                "`baz` is declared `pub(crate)` but `Foo` is only `pub(super)`, which limits its accessibility",
+            ]
+        );
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(visibility_check)]
+                struct Foo {
+                    #[get]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`bar` is declared `pub` but `Foo` is private, which limits its accessibility"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn push() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set(push_back)]
+                #[set(push_front)]
+                bar: VecDeque<u8>,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: VecDeque<u8>,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn push_back_bar(&mut self, value: u8) {
+                    ::std::collections::VecDeque::push_back(&mut self.bar, value);
+                }
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn push_front_bar(&mut self, value: u8) {
+                    ::std::collections::VecDeque::push_front(&mut self.bar, value);
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[set(push_back)]
+                    bar: Vec<u8>,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`push_back` requires a field of type `VecDeque<T>`"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pop() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(pop_front)]
+                #[get(pop_back)]
+                bar: VecDeque<u8>,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: VecDeque<u8>,
+            }
+
+            #[automatically_derived]
+
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn pop_front_bar(&mut self) -> ::core::option::Option<u8> {
+                    ::std::collections::VecDeque::pop_front(&mut self.bar)
+                }
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn pop_back_bar(&mut self) -> ::core::option::Option<u8> {
+                    ::std::collections::VecDeque::pop_back(&mut self.bar)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn transmute_to() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(transmute_to = f32)]
+                #[get(transmute_to = NonZeroU32)]
+                bar: u32,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u32,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                #[doc = "\n# Safety\n\nThe bits of `bar` must be a valid `f32`."]
+                pub unsafe fn bar_as_f32(&self) -> f32 {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::marker::Copy>() {}
+                        field_must_implement::<u32>();
+                    }
+                    const {
+                        ::core::assert!(
+                            ::core::mem::size_of::<u32>() == ::core::mem::size_of::<f32>(),
+                            "`transmute_to` requires the field and target types to have the same size",
+                        );
+                    }
+                    unsafe { ::core::mem::transmute_copy::<u32, f32>(&self.bar) }
+                }
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                #[doc = "\n# Safety\n\nThe bits of `bar` must be a valid `NonZeroU32`."]
+                pub unsafe fn bar_as_non_zero_u32(&self) -> NonZeroU32 {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::marker::Copy>() {}
+                        field_must_implement::<u32>();
+                    }
+                    const {
+                        ::core::assert!(
+                            ::core::mem::size_of::<u32>() == ::core::mem::size_of::<NonZeroU32>(),
+                            "`transmute_to` requires the field and target types to have the same size",
+                        );
+                    }
+                    unsafe { ::core::mem::transmute_copy::<u32, NonZeroU32>(&self.bar) }
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[get(transmute_to = [u8; 4])]
+                    bar: u32,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`transmute_to` requires a `name` unless the target is a plain type name"
+        );
+    }
+
+    #[test]
+    fn passthrough() {
+        let input = parse_quote! {
+            #[ters(passthrough)]
+            #[derive(Debug)]
+            struct Foo {
+                #[get(copy)]
+                #[set(name = "not an identifier")]
+                #[doc = "bar"]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            #[derive(Debug)]
+            struct Foo {
+                #[doc = "bar"]
+                bar: u8,
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn read_only() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(read_only)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        // `#[ters(set)]` leaves the field out rather than rejecting it
+        let out = expand(
+            Args::default(),
+            parse_quote! {
+                #[ters(set)]
+                struct Foo {
+                    #[get(read_only)]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[get(read_only)]
+                    #[set]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "setters cannot be generated for a field declared `#[get(read_only)]`",
+                "field declared read-only here",
+            ]
+        );
+    }
+
+    #[test]
+    fn into_fields() {
+        let input = parse_quote! {
+            #[ters(into_fields)]
+            pub struct Foo {
+                /// Bar.
+                #[get]
+                bar: u8,
+                baz: u8,
+                #[cfg(test)]
+                #[get(copy)]
+                #[get(clone, name = "qux_clone")]
+                qux: u8,
+            }
+        };
+
+        let expected = quote! {
+            pub struct Foo {
+                #[doc = r" Bar."]
+                bar: u8,
+                baz: u8,
+                #[cfg(test)]
+                qux: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[doc = r" Bar."]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+                #[cfg(test)]
+                #[doc = "Getter for `"]
+                #[doc = "qux"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn qux(&self) -> u8 {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::marker::Copy>() {}
+                        field_must_implement::<u8>();
+                    }
+                    self.qux
+                }
+                #[cfg(test)]
+                #[doc = "Getter for `"]
+                #[doc = "qux"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn qux_clone(&self) -> u8 {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::clone::Clone>() {}
+                        field_must_implement::<u8>();
+                    }
+                    ::core::clone::Clone::clone(&self.qux)
+                }
+            }
+
+            #[doc = "The fields of [`Foo`] which have getters, moved out of it."]
+            pub struct FooFields {
+                #[doc = r" Bar."]
+                pub bar: u8,
+                #[cfg(test)]
+                #[doc = "The `qux` field moved out of [`Foo`]."]
+                pub qux: u8,
+            }
+
+            #[automatically_derived]
+            impl ::core::convert::From<Foo> for FooFields {
+                fn from(value: Foo) -> Self {
+                    Self {
+                        bar: value.bar,
+                        #[cfg(test)]
+                        qux: value.qux,
+                    }
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(into_fields)]
+                struct Foo<T> {
+                    #[get]
+                    bar: T,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`into_fields` is not supported on generic structs"
+        );
+    }
+
+    #[test]
+    fn must_use() {
+        let input = parse_quote! {
+            #[must_use]
+            struct Foo {
+                #[get]
+                #[set]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            #[must_use]
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                #[must_use]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_bar(&mut self, value: u8) {
+                    self.bar = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn log_changes() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set(log_changes, normalize = normalize)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_bar(&mut self, value: u8) {
+                    let value = normalize(value);
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::fmt::Debug>() {}
+                        field_must_implement::<u8>();
+                    }
+                    ::ters::__private::log::debug!("bar: {:?} -> {:?}", self.bar, value);
+                    self.bar = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn hash_value() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(hash_value = FxHasher)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar_hash(&self) -> u64 {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::hash::Hash>() {}
+                        field_must_implement::<u8>();
+                    }
+                    let mut hasher = <FxHasher as ::core::default::Default>::default();
+                    ::core::hash::Hash::hash(&self.bar, &mut hasher);
+                    ::core::hash::Hasher::finish(&hasher)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn const_generics() {
+        let input = parse_quote! {
+            struct Foo<T, const N: usize = 4> where T: Copy {
+                #[get]
+                bar: [T; N],
+            }
+        };
+
+        let expected = quote! {
+            struct Foo<T, const N: usize = 4> where T: Copy {
+                bar: [T; N],
+            }
+
+            #[automatically_derived]
+            impl<T, const N: usize> Foo<T, N> where T: Copy {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &[T; N] {
+                    &self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn core_only() {
+        // everything available without the `std` feature must build without `alloc`
+        let input = parse_quote! {
+            #[ters(constructor)]
+            struct Foo {
+                #[get]
+                #[set(normalize = trim, builder)]
+                a: &'static str,
+                #[get(copy)]
+                #[set(bounded = "0..10")]
+                b: u8,
+                #[get(clone)]
+                #[set(unpack)]
+                c: (u8, u8),
+                #[get(opaque)]
+                d: [u8; 4],
+                #[get(format_with = hex)]
+                e: u32,
+                #[get(matches = Some(_), name = "has_f")]
+                f: Option<u8>,
+                #[get(ok)]
+                #[get(err)]
+                #[get(is_ok)]
+                g: Result<u8, u8>,
+                #[get(discriminant)]
+                h: Option<u8>,
+                #[get(transmute_to = f32)]
+                i: u32,
+                #[get(hash_value = Fnv)]
+                j: u64,
+            }
+        };
+
+        let out = expand(Args::default(), input).to_string();
+
+        assert!(!out.contains(":: std"), "{out}");
+        assert!(!out.contains(":: alloc"), "{out}");
+    }
+
+    #[test]
+    fn conflict_check() {
+        let input: syn::ItemStruct = parse_quote! {
+            #[ters(conflict_check)]
+            struct Foo {
+                #[get(name = "clone")]
+                #[set(name = "set_clone")]
+                bar: u8,
+                #[get]
+                #[set(name = "next")]
+                baz: u8,
+            }
+        };
+
+        let err = expand_err(Args::default(), input);
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "`clone` shadows `Clone::clone`, so method calls will resolve to the accessor instead",
+                "`next` shadows `Iterator::next`, so method calls will resolve to the accessor instead",
+            ]
+        );
+    }
+
+    #[test]
+    fn mutable_alias() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(with_mut, vis = "pub(crate)")]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub(crate) fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub(crate) fn bar_mut(&mut self) -> &mut u8 {
+                    &mut self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[get(mut_pair, copy)]
+                    bar: u8,
+                    #[get(mut, read_only)]
+                    baz: u8,
+                    #[get(mutable_alias)]
+                    #[get(mut)]
+                    qux: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "`mut_pair` cannot be combined with `copy`",
+                "a field declared `read_only` cannot have a mutable getter",
+                "conflicting accessor attributes: `qux_mut` is already generated for this field",
+                "`qux_mut` first declared here",
+            ]
+        );
+    }
+
+    #[test]
+    fn conditional() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set(conditional = is_valid, normalize = clamp)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_bar(&mut self, value: u8) -> bool {
+                    let value = clamp(value);
+                    if !is_valid(&value) {
+                        return false;
+                    }
+                    self.bar = value;
+                    true
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[set(builder, conditional = is_valid)]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`conditional` cannot be combined with `builder`"
+        );
+    }
+
+    #[test]
+    fn idempotent() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set(idempotent)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_bar(&mut self, value: u8) -> bool {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::cmp::PartialEq>() {}
+                        field_must_implement::<u8>();
+                    }
+                    if self.bar == value {
+                        return false;
+                    }
+                    self.bar = value;
+                    true
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[set(builder, idempotent)]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`idempotent` cannot be combined with `builder`"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn debug_str() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(debug_str)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar_debug_str(&self) -> ::std::string::String {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::fmt::Debug>() {}
+                        field_must_implement::<u8>();
+                    }
+                    ::std::format!("{:?}", self.bar)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn version() {
+        let input = parse_quote! {
+            #[ters(version = 2)]
+            struct Foo {
+                #[get]
+                #[set(vis = "pub")]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_bar(&mut self, value: u8) {
+                    self.bar = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(version = 3)]
+                struct Foo {
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "unsupported `version`; expected `1` or `2`"
+        );
+    }
+
+    #[test]
+    fn field_name_str() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(field_name_str)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar_as_str(&self) -> &'static str {
+                    ::core::stringify!(bar)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn setter_prefix() {
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(setter_prefix = "")]
+                struct Foo {
+                    #[get]
+                    #[set]
+                    bar: u8,
+                    #[get]
+                    #[set(name = "set_baz")]
+                    baz: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "empty setter prefix would produce method name `bar` which conflicts with getter"
+        );
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(setter_prefix = "1")]
+                struct Foo {
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(err.to_string(), "`1` is not a valid method prefix");
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn fn_ptr_setter() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set(fn_ptr)]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[doc = "\n# Safety\n\nThe pointer must be valid for writes of a `Foo`."]
+                pub const BAR_SETTER: unsafe extern "C" fn(*mut Foo, u8) = {
+                    unsafe extern "C" fn setter(ptr: *mut Foo, value: u8) {
+                        {
+                            fn field_must_implement<T: ?::core::marker::Sized + ::core::marker::Copy>() {}
+                            field_must_implement::<u8>();
+                        }
+                        unsafe {
+                            (*ptr).bar = value;
+                        }
+                    }
+
+                    setter
+                };
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo<T> {
+                    #[set(fn_ptr)]
+                    bar: T,
+                    #[set(fn_ptr, builder)]
+                    baz: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "`fn_ptr` cannot be combined with `builder`",
+                "`fn_ptr` is not supported on generic structs",
+            ]
+        );
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn fn_ptr_getter() {
+        let input = parse_quote! {
+            #[must_use]
+            struct Foo {
+                #[get(fn_ptr, name = "READ_BAR")]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            #[must_use]
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[doc = "\n# Safety\n\nThe pointer must be valid for reads of a `Foo`."]
+                pub const READ_BAR: unsafe extern "C" fn(*const Foo) -> u8 = {
+                    unsafe extern "C" fn getter(ptr: *const Foo) -> u8 {
+                        {
+                            fn field_must_implement<T: ?::core::marker::Sized + ::core::marker::Copy>() {}
+                            field_must_implement::<u8>();
+                        }
+                        unsafe { (*ptr).bar }
+                    }
+
+                    getter
+                };
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[get(fn_ptr, sized)]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(err.to_string(), "`fn_ptr` cannot be combined with `sized`");
+    }
+
+    #[test]
+    fn field_lists() {
+        let input = parse_quote! {
+            #[ters(get = [bar], set = [bar, baz])]
+            struct Foo {
+                bar: u8,
+                baz: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+                baz: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_bar(&mut self, value: u8) {
+                    self.bar = value;
+                }
+
+                #[doc = "Setter for `"]
+                #[doc = "baz"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn set_baz(&mut self, value: u8) {
+                    self.baz = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(get = [qux])]
+                struct Foo {
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`get` lists `qux`, but there is no field named `qux`"
+        );
+    }
+
+    #[test]
+    fn exclude() {
+        let input = parse_quote! {
+            #[ters(get, exclude = [_marker])]
+            struct Foo {
+                #[get(copy)]
+                bar: u8,
+                baz: u8,
+                _marker: (),
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+                baz: u8,
+                _marker: (),
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> u8 {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::marker::Copy>() {}
+                        field_must_implement::<u8>();
+                    }
+                    self.bar
+                }
+
+                #[doc = "Getter for `"]
+                #[doc = "baz"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn baz(&self) -> &u8 {
+                    &self.baz
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(exclude = [bar, qux])]
+                struct Foo {
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "`exclude` lists `qux`, but there is no field named `qux`",
+                "`exclude` only applies to `get` and `set` without field lists; add either as well",
+            ]
+        );
+    }
+
+    #[test]
+    fn fill_tuple_struct() {
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(get, set)]
+                struct Foo(u8, #[get(name = "baz")] u8);
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "`get` without a field list requires a struct with named fields; annotate the tuple struct fields with a `name` instead",
+                "`set` without a field list requires a struct with named fields; annotate the tuple struct fields with a `name` instead",
+            ]
+        );
+    }
+
+    #[test]
+    fn zip_adjacent() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(zip_adjacent)]
+                bar: u8,
+                baz: i8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+                baz: i8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn barbaz(&self) -> (&u8, &i8) {
+                    (&self.bar, &self.baz)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    bar: u8,
+                    #[get(zip_adjacent)]
+                    baz: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.to_string(),
+            "`zip_adjacent` cannot be applied to the last field of the struct"
+        );
+    }
+
+    #[test]
+    fn generate_builder_fn() {
+        let input = parse_quote! {
+            #[ters(generate_builder_fn)]
+            struct Foo<T> {
+                bar: T,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo<T> {
+                bar: T,
+            }
+
+            #[automatically_derived]
+            impl<T> Foo<T> {
+                #[doc = "Create a [`FooBuilder`] for building a `Foo`."]
+                #[inline]
+                pub fn builder() -> FooBuilder<T> {
+                    <FooBuilder<T> as ::core::default::Default>::default()
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn pub_fields() {
+        let input = parse_quote! {
+            pub struct Foo {
+                #[get]
+                pub bar: u32,
+            }
+        };
+
+        let expected = quote! {
+            pub struct Foo {
+                pub bar: u32,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u32 {
+                    &self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn atomic_fetch_update() {
+        let input = parse_quote! {
+            struct Foo {
+                #[set(atomic_fetch_update)]
+                bar: core::sync::atomic::AtomicU8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: core::sync::atomic::AtomicU8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Setter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn fetch_update_bar<F>(
+                    &self,
+                    set_order: ::core::sync::atomic::Ordering,
+                    fetch_order: ::core::sync::atomic::Ordering,
+                    f: F,
+                ) -> ::core::result::Result<u8, u8>
+                where
+                    F: ::core::ops::FnMut(u8) -> ::core::option::Option<u8>,
+                {
+                    <core::sync::atomic::AtomicU8>::fetch_update(&self.bar, set_order, fetch_order, f)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo {
+                    #[set(atomic_fetch_update)]
+                    bar: u8,
+                    #[set(atomic_fetch_update, normalize = clamp)]
+                    baz: AtomicU8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            [
+                "`atomic_fetch_update` cannot be combined with `normalize`",
+                "`atomic_fetch_update` requires a field of an atomic type, such as `AtomicU32`",
+            ]
+        );
+    }
+
+    #[test]
+    fn const_value() {
+        let input = parse_quote! {
+            struct Foo<const N: usize> {
+                #[get(const_value = N * 2)]
+                bar: usize,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo<const N: usize> {
+                bar: usize,
+            }
+
+            #[automatically_derived]
+            impl<const N: usize> Foo<N> {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub const fn bar() -> usize {
+                    N * 2
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn impl_from_tuple() {
+        let input = parse_quote! {
+            #[ters(impl_from_tuple)]
+            struct Point(#[get(name = "x")] f32, f32);
+        };
+
+        let expected = quote! {
+            struct Point(f32, f32);
+
+            #[automatically_derived]
+            impl Point {
+                #[doc = "Getter for `"]
+                #[doc = "0"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn x(&self) -> &f32 {
+                    &self.0
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::convert::From<(f32, f32,)> for Point {
+                fn from((_0, _1,): (f32, f32,)) -> Self {
+                    Self(_0, _1)
+                }
+            }
+
+            #[automatically_derived]
+            impl ::core::convert::From<Point> for (f32, f32,) {
+                fn from(value: Point) -> Self {
+                    (value.0, value.1,)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(impl_from_tuple)]
+                struct Foo {
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            ["`impl_from_tuple` requires a tuple struct"]
+        );
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                struct Foo(#[get] u8, #[set(name = "set_bar")] u8);
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            ["accessors of tuple struct fields require a `name`"]
+        );
+    }
+
+    #[test]
+    fn multiple_args() {
+        let input = parse_quote! {
+            #[ters(
+                get,
+                set,
+                vis = pub(crate),
+                setter_prefix = "with_",
+                rename_all = "camelCase",
+            )]
+            struct Foo {
+                bar_baz: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar_baz: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar_baz"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                #[allow(non_snake_case)]
+                pub(crate) fn barBaz(&self) -> &u8 {
+                    &self.bar_baz
+                }
+
+                #[doc = "Setter for `"]
+                #[doc = "bar_baz"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                #[allow(non_snake_case)]
+                pub(crate) fn withBarBaz(&mut self, value: u8) {
+                    self.bar_baz = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(get, rename_all = "kebab-case")]
+                struct Foo {
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            ["unsupported `rename_all`; expected `snake_case`, `camelCase`, or `PascalCase`"]
+        );
+    }
+
+    #[test]
+    fn phantom_skip() {
+        let input = parse_quote! {
+            #[ters(get, phantom_skip)]
+            struct Foo<T> {
+                bar: u8,
+                _marker: core::marker::PhantomData<fn() -> T>,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo<T> {
+                bar: u8,
+                _marker: core::marker::PhantomData<fn() -> T>,
+            }
+
+            #[automatically_derived]
+            impl<T> Foo<T> {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(phantom_skip)]
+                struct Foo {
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            ["`phantom_skip` only applies to `get` and `set` without field lists; add either as well"]
+        );
+    }
+
+    #[test]
+    fn debug_assert_some() {
+        let input = parse_quote! {
+            struct Foo {
+                #[get(debug_assert_some)]
+                bar: Option<u8>,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: Option<u8>,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> ::core::option::Option<&u8> {
+                    ::core::debug_assert!(::core::option::Option::is_some(&self.bar), "`bar` expected to be `Some`");
+                    ::core::option::Option::as_ref(&self.bar)
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn generate_index() {
+        let input = parse_quote! {
+            #[ters(generate_index)]
+            pub struct Foo {
+                #[get]
+                bar_baz: u8,
+            }
+        };
+
+        let expected = quote! {
+            pub struct Foo {
+                bar_baz: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar_baz"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar_baz(&self) -> &u8 {
+                    &self.bar_baz
+                }
+            }
+
+            #[doc = "The fields of [`Foo`] which have getters, for indexing it."]
+            #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::fmt::Debug, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+            pub enum FooField {
+                BarBaz,
+            }
+
+            #[automatically_derived]
+            impl ::core::ops::Index<FooField> for Foo {
+                type Output = u8;
+
+                fn index(&self, field: FooField) -> &Self::Output {
+                    match field {
+                        FooField::BarBaz => &self.bar_baz,
+                    }
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(generate_index)]
+                struct Foo {
+                    #[get]
+                    bar: u8,
+                    #[get]
+                    baz: u16,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            ["`generate_index` requires the fields with getters to share a type, `u8` here"]
+        );
+    }
+
+    #[test]
+    fn generate_mask() {
+        let input = parse_quote! {
+            #[ters(generate_mask)]
+            struct Foo {
+                bar: bool,
+                #[cfg(test)]
+                baz: bool,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: bool,
+                #[cfg(test)]
+                baz: bool,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                /// Pack the fields into the bits of a `u8`, in declaration order from the least significant bit.
+                #[inline]
+                pub fn to_u8(&self) -> u8 {
+                    let mut mask = 0;
+                    {
+                        mask |= <u8 as ::core::convert::From<bool>>::from(self.bar) << 0u8;
+                    }
+                    #[cfg(test)]
+                    {
+                        mask |= <u8 as ::core::convert::From<bool>>::from(self.baz) << 1u8;
+                    }
+                    mask
+                }
+
+                /// Unpack the fields from the bits of a `u8`, in declaration order from the least significant bit.
+                ///
+                /// Bits beyond the fields are ignored.
+                #[inline]
+                pub fn from_u8(mask: u8) -> Self {
+                    Self {
+                        bar: mask & (1 << 0u8) != 0,
+                        #[cfg(test)]
+                        baz: mask & (1 << 1u8) != 0
+                    }
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(generate_mask)]
+                struct Foo {
+                    bar: bool,
+                    baz: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            ["`generate_mask` requires every field to be a `bool`"]
+        );
+    }
+
+    #[test]
+    fn try_from_int() {
+        let item = parse_quote! {
+            enum Foo {
+                Bar(u8),
+                Baz,
+            }
+        };
+
+        let err =
+            crate::try_from_int::expand(&item, &parse_quote!(u8), proc_macro2::Span::call_site())
+                .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "`try_from_int` requires an enum whose variants have no fields"
+        );
+    }
+
+    #[test]
+    fn non_exhaustive_fields() {
+        let input = parse_quote! {
+            #[ters(into_fields)]
+            #[non_exhaustive]
+            pub struct Foo {
+                #[get]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            #[non_exhaustive]
+            pub struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+            }
+
+            #[doc = "The fields of [`Foo`] which have getters, moved out of it."]
+            #[non_exhaustive]
+            pub struct FooFields {
+                #[doc = "The `bar` field moved out of [`Foo`]."]
+                pub bar: u8,
+            }
+
+            #[automatically_derived]
+            impl ::core::convert::From<Foo> for FooFields {
+                fn from(value: Foo) -> Self {
+                    Self {
+                        bar: value.bar,
+                    }
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn thiserror() {
+        let input = parse_quote! {
+            #[derive(Debug, thiserror::Error)]
+            #[error("unexpected input at {position}")]
+            struct ParseError {
+                #[get]
+                input: String,
+                #[get(copy)]
+                position: usize,
+            }
+        };
+
+        let expected = quote! {
+            #[derive(Debug, thiserror::Error)]
+            #[error("unexpected input at {position}")]
+            struct ParseError {
+                input: String,
+                position: usize,
+            }
+
+            #[automatically_derived]
+            impl ParseError {
+                #[doc = "Getter for `"]
+                #[doc = "input"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn input(&self) -> &String {
+                    &self.input
+                }
+                #[doc = "Getter for `"]
+                #[doc = "position"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn position(&self) -> usize {
+                    {
+                        fn field_must_implement<T: ?::core::marker::Sized + ::core::marker::Copy>() {}
+                        field_must_implement::<usize>();
+                    }
+                    self.position
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn generate_update_fn() {
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(generate_update_fn)]
+                struct Foo {
+                    #[get]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            ["`generate_update_fn` requires at least one field with a setter"]
+        );
+    }
+
+    #[test]
+    fn no_docs() {
+        let input = parse_quote! {
+            #[ters(no_docs)]
+            struct Foo {
+                /// The bar.
+                #[get]
+                bar: u8,
+                #[set]
+                baz: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                #[doc = r" The bar."]
+                bar: u8,
+                baz: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = r" The bar."]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+                #[inline]
+                pub fn set_baz(&mut self, value: u8) {
+                    self.baz = value;
+                }
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn generate_snapshot() {
+        let input = parse_quote! {
+            #[ters(generate_snapshot)]
+            pub struct Foo {
+                #[get]
+                bar: u8,
+                baz: u8,
+            }
+        };
+
+        let expected = quote! {
+            pub struct Foo {
+                bar: u8,
+                baz: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Clone the fields which have getters into a [`FooSnapshot`]."]
+                #[inline]
+                pub fn snapshot(&self) -> FooSnapshot {
+                    FooSnapshot {
+                        bar: ::core::clone::Clone::clone(&self.bar),
+                    }
+                }
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+            }
+
+            #[doc = "Clones of the fields of [`Foo`] which have getters, taken by [`Foo::snapshot`]."]
+            #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq)]
+            pub struct FooSnapshot {
+                #[doc = "Clone of `bar`."]
+                pub bar: u8,
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn generate_diff() {
+        let input = parse_quote! {
+            #[ters(generate_diff)]
+            struct Foo {
+                #[get]
+                bar: u8,
+            }
+        };
+
+        let expected = quote! {
+            struct Foo {
+                bar: u8,
+            }
+
+            #[automatically_derived]
+            impl Foo {
+                #[doc = "Compare the fields which have getters, cloning those of `other` which differ into a [`FooDiff`]."]
+                #[inline]
+                pub fn diff(&self, other: &Self) -> FooDiff {
+                    FooDiff {
+                        bar: if ::core::cmp::PartialEq::ne(&self.bar, &other.bar) {
+                            ::core::option::Option::Some(::core::clone::Clone::clone(&other.bar))
+                        } else {
+                            ::core::option::Option::None
+                        },
+                    }
+                }
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &u8 {
+                    &self.bar
+                }
+            }
+
+            #[doc = "The fields of [`Foo`] which have getters that differ, from [`Foo::diff`]."]
+            #[derive(::core::fmt::Debug)]
+            struct FooDiff {
+                #[doc = "The value of `bar` in `other`, if it differs."]
+                bar: ::core::option::Option<u8>,
+            }
+        };
+
+        let out = expand(Args::default(), input);
+
+        assert_eq!(out.to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn generate_merge() {
+        let err = expand_err(
+            Args::default(),
+            parse_quote! {
+                #[ters(generate_merge)]
+                struct Foo {
+                    #[get]
+                    bar: u8,
+                }
+            },
+        );
+
+        assert_eq!(
+            err.into_iter().map(|e| e.to_string()).collect::<Vec<_>>(),
+            ["`generate_merge` applies the diffs of `generate_diff`; add it as well"]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn generate_serde_accessor_trait() {
+        let input = parse_quote! {
+            #[ters(generate_serde_accessor_trait)]
+            pub struct Foo<T> {
+                #[get]
+                bar: T,
+                baz: u8,
+            }
+        };
+
+        let expected = quote! {
+            pub struct Foo<T> {
+                bar: T,
+                baz: u8,
+            }
+
+            #[automatically_derived]
+            impl<T> Foo<T> {
+                #[doc = "Getter for `"]
+                #[doc = "bar"]
+                #[doc = "`.\n\n"]
+                #[inline]
+                pub fn bar(&self) -> &T {
+                    &self.bar
+                }
+            }
+
+            #[doc = "Accessors serializing the fields of [`Foo`] which have getters to JSON values."]
+            pub trait FooSerdeAccessors {
+                #[doc = "Serialize `bar` to a JSON value."]
+                fn bar_json(&self) -> ::core::result::Result<::ters::__private::serde_json::Value, ::ters::__private::serde_json::Error>;
+            }
+
+            #[automatically_derived]
+            impl<T> FooSerdeAccessors for Foo<T>
+            where
+                T: ::ters::__private::serde::Serialize
+            {
+                #[inline]
+                fn bar_json(&self) -> ::core::result::Result<::ters::__private::serde_json::Value, ::ters::__private::serde_json::Error> {
+                    ::ters::__private::serde_json::to_value(&self.bar)
                 }
             }
         };
 
-        let out: proc_macro2::TokenStream = ters_inner(input).into();
+        let out = expand(Args::default(), input);
 
         assert_eq!(out.to_string(), expected.to_string());
     }
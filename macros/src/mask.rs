@@ -0,0 +1,81 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{parse_quote, ItemStruct, Type, Visibility};
+
+use crate::{args::Args, field::Field};
+
+/// Generate the `to_u8` and `from_u8` methods requested by `#[ters(generate_mask)]`,
+/// packing the `bool` fields into bits in declaration order, least significant first.
+pub fn expand(
+    item: &ItemStruct,
+    fields: &[Field],
+    args: &Args,
+    span: Span,
+) -> syn::Result<TokenStream> {
+    let mut not_bool = fields
+        .iter()
+        .filter(|field| !is_bool(&field.ty))
+        .map(|field| {
+            syn::Error::new_spanned(
+                &field.ty,
+                "`generate_mask` requires every field to be a `bool`",
+            )
+        });
+
+    if let Some(mut e) = not_bool.next() {
+        not_bool.for_each(|other| e.combine(other));
+        return Err(e);
+    }
+
+    if fields.len() > 8 {
+        return Err(syn::Error::new(
+            span,
+            format!(
+                "`generate_mask` packs at most 8 fields into a `u8`, but `{}` has {}",
+                item.ident,
+                fields.len()
+            ),
+        ));
+    }
+
+    let members = fields.iter().map(|field| &field.member).collect::<Vec<_>>();
+    let cfgs = fields
+        .iter()
+        .map(|field| field.cfg_attrs())
+        .collect::<Vec<_>>();
+    let bits = (0..fields.len() as u8).collect::<Vec<_>>();
+    let vis: Visibility = args.default_vis().unwrap_or(parse_quote!(pub));
+
+    // fields configured out leave their bits unused, so the others keep theirs
+    Ok(quote! {
+        /// Pack the fields into the bits of a `u8`, in declaration order from the least significant bit.
+        #[inline]
+        #vis fn to_u8(&self) -> u8 {
+            let mut mask = 0;
+            #(
+                #(#cfgs)*
+                {
+                    mask |= <u8 as ::core::convert::From<bool>>::from(self.#members) << #bits;
+                }
+            )*
+            mask
+        }
+
+        /// Unpack the fields from the bits of a `u8`, in declaration order from the least significant bit.
+        ///
+        /// Bits beyond the fields are ignored.
+        #[inline]
+        #vis fn from_u8(mask: u8) -> Self {
+            Self {
+                #(
+                    #(#cfgs)*
+                    #members: mask & (1 << #bits) != 0
+                ),*
+            }
+        }
+    })
+}
+
+fn is_bool(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("bool"))
+}
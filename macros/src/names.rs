@@ -0,0 +1,177 @@
+use proc_macro2::Span;
+use syn::{
+    ext::IdentExt,
+    parse::{ParseStream, Parser},
+    Attribute, Ident, LitStr, Visibility,
+};
+
+/// Parse a user supplied method name, which must be a valid (possibly raw) identifier.
+///
+/// Leading and trailing underscores as well as non-ASCII identifiers are accepted,
+/// just as they are by rustc.
+pub fn parse_method_name(lit: &LitStr) -> syn::Result<Ident> {
+    let value = lit.value();
+
+    if let Ok(mut ident) = syn::parse_str::<Ident>(&value) {
+        ident.set_span(lit.span());
+        return Ok(ident);
+    }
+
+    let message = match Ident::parse_any.parse_str(&value) {
+        Ok(keyword) if ["self", "Self", "super", "crate"].contains(&value.as_str()) => {
+            format!("`{keyword}` cannot be used as a method name")
+        }
+        Ok(keyword) => {
+            format!("`{keyword}` is a keyword; use a raw identifier (`r#{keyword}`) instead")
+        }
+        Err(_) => format!("`{value}` is not a valid identifier"),
+    };
+
+    Err(syn::Error::new_spanned(lit, message))
+}
+
+/// Parse a user supplied visibility, such as `"pub(crate)"`, or `""` for private.
+///
+/// The visibility may also be written bare, as in `vis = pub(crate)`.
+pub fn parse_vis(input: ParseStream) -> syn::Result<Visibility> {
+    if !input.peek(LitStr) {
+        return input.parse();
+    }
+
+    let lit = input.parse::<LitStr>()?;
+
+    lit.parse().map_err(|_| {
+        syn::Error::new_spanned(&lit, format!("`{}` is not a valid visibility", lit.value()))
+    })
+}
+
+/// Methods of standard traits which are commonly implemented or called on structs,
+/// and would be shadowed by an inherent method of the same name.
+const TRAIT_METHODS: &[(&str, &str)] = &[
+    ("as_mut", "AsMut::as_mut"),
+    ("as_ref", "AsRef::as_ref"),
+    ("borrow", "Borrow::borrow"),
+    ("borrow_mut", "BorrowMut::borrow_mut"),
+    ("clamp", "Ord::clamp"),
+    ("clone", "Clone::clone"),
+    ("clone_from", "Clone::clone_from"),
+    ("cmp", "Ord::cmp"),
+    ("default", "Default::default"),
+    ("deref", "Deref::deref"),
+    ("deref_mut", "DerefMut::deref_mut"),
+    ("drop", "Drop::drop"),
+    ("eq", "PartialEq::eq"),
+    ("fmt", "Debug::fmt"),
+    ("from", "From::from"),
+    ("ge", "PartialOrd::ge"),
+    ("gt", "PartialOrd::gt"),
+    ("hash", "Hash::hash"),
+    ("into", "Into::into"),
+    ("into_iter", "IntoIterator::into_iter"),
+    ("le", "PartialOrd::le"),
+    ("lt", "PartialOrd::lt"),
+    ("max", "Ord::max"),
+    ("min", "Ord::min"),
+    ("ne", "PartialEq::ne"),
+    ("next", "Iterator::next"),
+    ("partial_cmp", "PartialOrd::partial_cmp"),
+    ("to_owned", "ToOwned::to_owned"),
+    ("to_string", "ToString::to_string"),
+    ("try_from", "TryFrom::try_from"),
+    ("try_into", "TryInto::try_into"),
+];
+
+/// Where a generated method originates from.
+enum Origin<'a> {
+    /// The accessor declared by an attribute on a field.
+    Accessor(&'a Ident, &'a Attribute),
+    /// The method requested by a `#[ters]` option, spanned to the option.
+    Option(&'static str, Span),
+}
+
+/// The names of generated methods, along with the field and attribute or option each originates from.
+#[derive(Default)]
+pub struct Names<'a>(Vec<(Ident, Origin<'a>)>);
+
+impl<'a> Names<'a> {
+    /// Claim a method name for the accessor declared by `attr` on `field`.
+    ///
+    /// Fails if an earlier accessor already claimed the name, whether on the same field
+    /// (conflicting attributes) or another (colliding names), or an option did.
+    pub fn claim(
+        &mut self,
+        method: Ident,
+        field: &'a Ident,
+        attr: &'a Attribute,
+    ) -> syn::Result<()> {
+        let Some((_, first)) = self.0.iter().find(|(claimed, _)| *claimed == method) else {
+            self.0.push((method, Origin::Accessor(field, attr)));
+            return Ok(());
+        };
+
+        let mut e;
+
+        match first {
+            Origin::Accessor(first_field, first_attr) if *first_field == field => {
+                e = syn::Error::new_spanned(
+                    attr,
+                    format!("conflicting accessor attributes: `{method}` is already generated for this field"),
+                );
+                e.combine(syn::Error::new_spanned(
+                    first_attr,
+                    format!("`{method}` first declared here"),
+                ));
+            }
+            Origin::Accessor(first_field, _) => {
+                e = syn::Error::new_spanned(
+                    field,
+                    format!("method `{method}` generated for field `{field}` collides with a method generated for field `{first_field}`"),
+                );
+                e.combine(syn::Error::new_spanned(
+                    first_field,
+                    format!("`{method}` first generated for this field"),
+                ));
+            }
+            Origin::Option(option, span) => {
+                e = syn::Error::new_spanned(
+                    field,
+                    format!("method `{method}` generated for field `{field}` collides with the method generated by `{option}`"),
+                );
+                e.combine(syn::Error::new(
+                    *span,
+                    format!("`{method}` first generated by this option"),
+                ));
+            }
+        }
+
+        Err(e)
+    }
+
+    /// Claim a method name for the method requested by `option`, spanned to the option.
+    ///
+    /// Options are claimed before any accessor, so only accessors can collide with them.
+    pub fn claim_option(&mut self, method: &str, option: &'static str, span: Span) {
+        self.0
+            .push((Ident::new(method, span), Origin::Option(option, span)));
+    }
+
+    /// The claimed method names which shadow a method of a standard trait,
+    /// along with the attribute declaring them and the trait method shadowed.
+    pub fn shadowing(&self) -> impl Iterator<Item = (&Ident, &Attribute, &'static str)> {
+        self.0.iter().filter_map(|(method, origin)| {
+            let Origin::Accessor(_, attr) = origin else {
+                return None;
+            };
+
+            TRAIT_METHODS
+                .iter()
+                .find(|(name, _)| method == name)
+                .map(|(_, path)| (method, *attr, *path))
+        })
+    }
+
+    /// The claimed method names, options first and then accessors in order of declaration.
+    pub fn methods(&self) -> impl Iterator<Item = &Ident> {
+        self.0.iter().map(|(method, _)| method)
+    }
+}
@@ -0,0 +1,69 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{spanned::Spanned, ItemStruct};
+
+use crate::field::Field;
+
+/// Generate the `{Struct}SerdeAccessors` trait requested by
+/// `#[ters(generate_serde_accessor_trait)]`, with a method serializing each field which
+/// has a getter to a `serde_json::Value`, along with its impl for the struct.
+pub fn expand(item: &ItemStruct, fields: &[Field]) -> TokenStream {
+    let ItemStruct { vis, ident, .. } = item;
+    let trait_ident = format_ident!("{ident}SerdeAccessors");
+    let doc = format!(
+        "Accessors serializing the fields of [`{ident}`] which have getters to JSON values."
+    );
+
+    let fields = fields
+        .iter()
+        .filter(|field| !field.getters.is_empty())
+        .collect::<Vec<_>>();
+
+    let mut generics = item.generics.clone();
+    let where_clause = generics.make_where_clause();
+
+    for field in &fields {
+        let ty = &field.ty;
+
+        where_clause.predicates.push(
+            syn::parse_quote_spanned! {ty.span()=> #ty: ::ters::__private::serde::Serialize },
+        );
+    }
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let cfgs = fields
+        .iter()
+        .map(|field| field.cfg_attrs())
+        .collect::<Vec<_>>();
+    let methods = fields
+        .iter()
+        .map(|field| format_ident!("{}_json", field.ident))
+        .collect::<Vec<_>>();
+    let method_docs = fields
+        .iter()
+        .map(|field| format!("Serialize `{}` to a JSON value.", field.name()));
+    let members = fields.iter().map(|field| &field.member);
+
+    quote! {
+        #[doc = #doc]
+        #vis trait #trait_ident {
+            #(
+                #(#cfgs)*
+                #[doc = #method_docs]
+                fn #methods(&self) -> ::core::result::Result<::ters::__private::serde_json::Value, ::ters::__private::serde_json::Error>;
+            )*
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #trait_ident for #ident #ty_generics #where_clause {
+            #(
+                #(#cfgs)*
+                #[inline]
+                fn #methods(&self) -> ::core::result::Result<::ters::__private::serde_json::Value, ::ters::__private::serde_json::Error> {
+                    ::ters::__private::serde_json::to_value(&self.#members)
+                }
+            )*
+        }
+    }
+}
@@ -0,0 +1,535 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse_quote, spanned::Spanned, token::Paren, Attribute, ExprRange, Ident, LitStr, Meta, Path,
+    Type, Visibility,
+};
+
+use crate::{
+    args::Args,
+    bounds::assert_bound,
+    case::Case,
+    ffi,
+    field::Field,
+    names::{parse_method_name, parse_vis},
+    types::{atomic_value, generic_arg},
+};
+
+/// An end of a `VecDeque` to push onto instead of replacing the field.
+#[derive(Clone, Copy)]
+enum End {
+    Front,
+    Back,
+}
+
+impl End {
+    /// The option selecting this end, which is also the `VecDeque` method name and method prefix.
+    fn keyword(self) -> &'static str {
+        match self {
+            Self::Front => "push_front",
+            Self::Back => "push_back",
+        }
+    }
+}
+
+/// Options accepted by `#[set(...)]`.
+#[derive(Default)]
+pub struct Setter {
+    /// Overrides the generated method name.
+    name: Option<Ident>,
+    /// Overrides the visibility of the generated method, which is `pub` by default.
+    vis: Option<Visibility>,
+    /// Replaces the `set_` prefix of the generated method name.
+    prefix: Option<String>,
+    /// The case the generated method name is converted to, unless given a `name`.
+    rename_all: Option<Case>,
+    /// Function applied to the value before it is stored.
+    normalize: Option<Path>,
+    /// Take and return `self` by value for chaining, generating a `with_` setter.
+    builder: bool,
+    /// Take the elements of a tuple field as separate parameters, optionally named.
+    unpack: Option<Option<Vec<Ident>>>,
+    /// Range the value is debug-asserted to lie within, alongside its source text.
+    bounded: Option<(ExprRange, String)>,
+    /// Push onto an end of a `VecDeque` field instead of replacing it.
+    push: Option<End>,
+    /// Log the old and new values with `log::debug!`.
+    log_changes: Option<Span>,
+    /// Predicate the value must satisfy to be stored, reported by returning `bool`.
+    conditional: Option<Path>,
+    /// Skip storing values equal to the current one, reported by returning `bool`.
+    idempotent: Option<Span>,
+    /// Generate an `extern "C"` function pointer constant instead of a method.
+    fn_ptr: Option<Span>,
+    /// Wrap `fetch_update` of an atomic field instead of replacing it.
+    atomic_fetch_update: Option<Span>,
+}
+
+impl Setter {
+    pub fn parse(attr: &Attribute, args: &Args) -> syn::Result<Self> {
+        let mut setter = Self::default();
+
+        if let Meta::List(_) = attr.meta {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("name") {
+                    if setter.name.is_some() {
+                        return Err(meta.error("duplicate `name` option"));
+                    }
+
+                    setter.name = Some(parse_method_name(&meta.value()?.parse()?)?);
+                    Ok(())
+                } else if meta.path.is_ident("vis") {
+                    if setter.vis.is_some() {
+                        return Err(meta.error("duplicate `vis` option"));
+                    }
+
+                    setter.vis = Some(parse_vis(meta.value()?)?);
+                    Ok(())
+                } else if meta.path.is_ident("normalize") {
+                    if setter.normalize.is_some() {
+                        return Err(meta.error("duplicate `normalize` option"));
+                    }
+
+                    setter.normalize = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("builder") {
+                    if setter.builder {
+                        return Err(meta.error("duplicate `builder` option"));
+                    }
+
+                    setter.builder = true;
+                    Ok(())
+                } else if meta.path.is_ident("bounded") {
+                    if setter.bounded.is_some() {
+                        return Err(meta.error("duplicate `bounded` option"));
+                    }
+
+                    let lit = meta.value()?.parse::<LitStr>()?;
+                    setter.bounded = Some((lit.parse()?, lit.value()));
+                    Ok(())
+                } else if meta.path.is_ident("push_front") || meta.path.is_ident("push_back") {
+                    let end = if meta.path.is_ident("push_front") {
+                        End::Front
+                    } else {
+                        End::Back
+                    };
+
+                    if !cfg!(feature = "std") {
+                        return Err(meta.error(format!(
+                            "`{}` requires the `std` feature of `ters`",
+                            end.keyword()
+                        )));
+                    }
+
+                    if let Some(push) = setter.push {
+                        return Err(meta.error(format!(
+                            "`{}` cannot be combined with `{}`",
+                            end.keyword(),
+                            push.keyword()
+                        )));
+                    }
+
+                    setter.push = Some(end);
+                    Ok(())
+                } else if meta.path.is_ident("log_changes") {
+                    if !cfg!(feature = "log") {
+                        return Err(
+                            meta.error("`log_changes` requires the `log` feature of `ters`")
+                        );
+                    }
+
+                    if setter.log_changes.is_some() {
+                        return Err(meta.error("duplicate `log_changes` option"));
+                    }
+
+                    setter.log_changes = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("conditional") {
+                    if setter.conditional.is_some() {
+                        return Err(meta.error("duplicate `conditional` option"));
+                    }
+
+                    setter.conditional = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else if meta.path.is_ident("idempotent") {
+                    if setter.idempotent.is_some() {
+                        return Err(meta.error("duplicate `idempotent` option"));
+                    }
+
+                    setter.idempotent = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("fn_ptr") {
+                    if !cfg!(feature = "ffi") {
+                        return Err(meta.error("`fn_ptr` requires the `ffi` feature of `ters`"));
+                    }
+
+                    if setter.fn_ptr.is_some() {
+                        return Err(meta.error("duplicate `fn_ptr` option"));
+                    }
+
+                    setter.fn_ptr = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("atomic_fetch_update") {
+                    if setter.atomic_fetch_update.is_some() {
+                        return Err(meta.error("duplicate `atomic_fetch_update` option"));
+                    }
+
+                    setter.atomic_fetch_update = Some(meta.path.span());
+                    Ok(())
+                } else if meta.path.is_ident("unpack") {
+                    if setter.unpack.is_some() {
+                        return Err(meta.error("duplicate `unpack` option"));
+                    }
+
+                    let names = if meta.input.peek(Paren) {
+                        let mut names = Vec::new();
+                        meta.parse_nested_meta(|meta| {
+                            names.push(meta.path.require_ident()?.clone());
+                            Ok(())
+                        })?;
+
+                        Some(names)
+                    } else {
+                        None
+                    };
+
+                    setter.unpack = Some(names);
+                    Ok(())
+                } else {
+                    Err(meta.error("unrecognized setter option"))
+                }
+            })?;
+        }
+
+        if setter.vis.is_none() {
+            setter.vis = args.default_vis();
+        }
+
+        setter.prefix.clone_from(&args.setter_prefix);
+        setter.rename_all = args.rename_all;
+
+        if let (Some(end), Some(span)) = (setter.push, setter.log_changes) {
+            return Err(syn::Error::new(
+                span,
+                format!("`log_changes` cannot be combined with `{}`", end.keyword()),
+            ));
+        }
+
+        if let (Some(end), Some(span)) = (setter.push, setter.idempotent) {
+            return Err(syn::Error::new(
+                span,
+                format!("`idempotent` cannot be combined with `{}`", end.keyword()),
+            ));
+        }
+
+        // these replace the setter's usual signature and body entirely
+        let exclusive = [
+            ("fn_ptr", setter.fn_ptr),
+            ("atomic_fetch_update", setter.atomic_fetch_update),
+        ];
+
+        for (exclusive, span) in exclusive {
+            let Some(span) = span else {
+                continue;
+            };
+
+            let options = [
+                ("normalize", setter.normalize.is_some()),
+                ("builder", setter.builder),
+                ("unpack", setter.unpack.is_some()),
+                ("bounded", setter.bounded.is_some()),
+                ("push_front", matches!(setter.push, Some(End::Front))),
+                ("push_back", matches!(setter.push, Some(End::Back))),
+                ("log_changes", setter.log_changes.is_some()),
+                ("conditional", setter.conditional.is_some()),
+                ("idempotent", setter.idempotent.is_some()),
+                ("fn_ptr", setter.fn_ptr.is_some()),
+                ("atomic_fetch_update", setter.atomic_fetch_update.is_some()),
+            ];
+
+            if let Some((option, _)) = options
+                .iter()
+                .find(|(option, set)| *set && *option != exclusive)
+            {
+                return Err(syn::Error::new(
+                    span,
+                    format!("`{exclusive}` cannot be combined with `{option}`"),
+                ));
+            }
+        }
+
+        // builders already return `Self`, leaving no room to report whether the value was stored
+        if setter.builder {
+            if let Some(conditional) = &setter.conditional {
+                return Err(syn::Error::new_spanned(
+                    conditional,
+                    "`conditional` cannot be combined with `builder`",
+                ));
+            }
+
+            if let Some(span) = setter.idempotent {
+                return Err(syn::Error::new(
+                    span,
+                    "`idempotent` cannot be combined with `builder`",
+                ));
+            }
+        }
+
+        Ok(setter)
+    }
+
+    /// Whether the method is named explicitly rather than after the field.
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    /// The visibility of the generated method.
+    pub fn vis(&self) -> Visibility {
+        self.vis.clone().unwrap_or(parse_quote!(pub))
+    }
+
+    /// The name of the generated method.
+    pub fn ident(&self, field: &Ident) -> Ident {
+        let ident = match (&self.name, self.push) {
+            (Some(name), _) => return name.clone(),
+            (None, _) if self.fn_ptr.is_some() => return ffi::const_ident(field, "SETTER"),
+            (None, _) if self.atomic_fetch_update.is_some() => {
+                format_ident!("fetch_update_{field}")
+            }
+            (None, Some(end)) => format_ident!("{}_{field}", end.keyword()),
+            (None, None) if self.builder => format_ident!("with_{field}"),
+            (None, None) => format_ident!("{}{field}", self.prefix.as_deref().unwrap_or("set_")),
+        };
+
+        match self.rename_all {
+            Some(case) => case.apply(&ident),
+            None => ident,
+        }
+    }
+
+    /// The span of the `fn_ptr` option, if a function pointer constant is requested instead.
+    pub fn fn_ptr(&self) -> Option<Span> {
+        self.fn_ptr
+    }
+
+    /// Whether the generated method is named after the field alone, due to an empty prefix.
+    pub fn is_bare(&self) -> bool {
+        self.name.is_none()
+            && self.push.is_none()
+            && !self.builder
+            && self.fn_ptr.is_none()
+            && self.atomic_fetch_update.is_none()
+            && self.prefix.as_deref() == Some("")
+    }
+
+    /// Whether the generated method takes `&mut self` and the field's whole value.
+    pub fn takes_value(&self) -> bool {
+        !self.builder
+            && self.unpack.is_none()
+            && self.push.is_none()
+            && self.fn_ptr.is_none()
+            && self.atomic_fetch_update.is_none()
+    }
+
+    /// Generate the setter method for `field`.
+    pub fn expand(&self, field: &Field) -> syn::Result<TokenStream> {
+        let Field {
+            ident, member, ty, ..
+        } = field;
+        let method_ident = self.ident(ident);
+        let vis = self.vis();
+
+        // atomics are updated through a shared reference
+        if self.atomic_fetch_update.is_some() {
+            let value = atomic_value(ty).ok_or_else(|| {
+                syn::Error::new_spanned(
+                    ty,
+                    "`atomic_fetch_update` requires a field of an atomic type, such as `AtomicU32`",
+                )
+            })?;
+
+            return Ok(quote! {
+                #vis fn #method_ident<F>(
+                    &self,
+                    set_order: ::core::sync::atomic::Ordering,
+                    fetch_order: ::core::sync::atomic::Ordering,
+                    f: F,
+                ) -> ::core::result::Result<#value, #value>
+                where
+                    F: ::core::ops::FnMut(#value) -> ::core::option::Option<#value>,
+                {
+                    <#ty>::fetch_update(&self.#member, set_order, fetch_order, f)
+                }
+            });
+        }
+
+        // pushing setters take elements rather than the whole field
+        let ty = match self.push {
+            Some(end) => generic_arg(ty, "VecDeque").ok_or_else(|| {
+                syn::Error::new_spanned(
+                    ty,
+                    format!("`{}` requires a field of type `VecDeque<T>`", end.keyword()),
+                )
+            })?,
+            None => ty,
+        };
+
+        let (params, value) = match &self.unpack {
+            Some(names) => {
+                let (names, tys) = unpacked_params(ty, names.as_deref())?;
+
+                (quote! { #(#names: #tys),* }, quote! { (#(#names),*,) })
+            }
+            None => (quote! { value: #ty }, quote! { value }),
+        };
+
+        let value = match &self.normalize {
+            Some(normalize) => quote! { #normalize(#value) },
+            None => value,
+        };
+
+        // the stored value is checked and logged after unpacking and normalization
+        let inspected = self.bounded.is_some()
+            || self.log_changes.is_some()
+            || self.conditional.is_some()
+            || self.idempotent.is_some();
+        let binding = (inspected && (self.unpack.is_some() || self.normalize.is_some()))
+            .then(|| quote! { let value = #value; });
+        let value = if inspected {
+            quote! { value }
+        } else {
+            value
+        };
+
+        let condition = self.conditional.as_ref().map(|conditional| {
+            quote! {
+                if !#conditional(&value) {
+                    return false;
+                }
+            }
+        });
+
+        let unchanged = self.idempotent.map(|span| {
+            let bound = assert_bound(ty, quote_spanned! {span=> ::core::cmp::PartialEq }, span);
+
+            quote! {
+                #bound
+                if self.#member == value {
+                    return false;
+                }
+            }
+        });
+
+        let assertion = self.bounded.as_ref().map(|(range, source)| {
+            let message = format!("`{}` must be within `{source}`, got {{:?}}", field.name());
+
+            quote! {
+                ::core::debug_assert!((#range).contains(&value), #message, value);
+            }
+        });
+
+        let log = self.log_changes.map(|span| {
+            let bound = assert_bound(ty, quote_spanned! {span=> ::core::fmt::Debug }, span);
+            let message = format!("{}: {{:?}} -> {{:?}}", field.name());
+
+            quote! {
+                #bound
+                ::ters::__private::log::debug!(#message, self.#member, value);
+            }
+        });
+
+        let store = match self.push {
+            Some(end) => {
+                let method = format_ident!("{}", end.keyword());
+
+                quote! { ::std::collections::VecDeque::#method(&mut self.#member, #value); }
+            }
+            None => quote! { self.#member = #value; },
+        };
+
+        Ok(if self.builder {
+            quote! {
+                #[must_use = "this method returns Self for chaining"]
+                #vis fn #method_ident(mut self, #params) -> Self {
+                    #binding
+                    #assertion
+                    #log
+                    #store
+                    self
+                }
+            }
+        } else if self.conditional.is_some() || self.idempotent.is_some() {
+            quote! {
+                #vis fn #method_ident(&mut self, #params) -> bool {
+                    #binding
+                    #condition
+                    #unchanged
+                    #assertion
+                    #log
+                    #store
+                    true
+                }
+            }
+        } else {
+            quote! {
+                #vis fn #method_ident(&mut self, #params) {
+                    #binding
+                    #assertion
+                    #log
+                    #store
+                }
+            }
+        })
+    }
+}
+
+/// The parameter names and types of an unpacking setter for a field of tuple type `ty`.
+///
+/// Without explicit `names`, parameters are named `a`, `b`, `c`, and so on.
+fn unpacked_params<'a>(
+    ty: &'a Type,
+    names: Option<&[Ident]>,
+) -> syn::Result<(Vec<Ident>, Vec<&'a Type>)> {
+    let Type::Tuple(tuple) = ty else {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "`unpack` requires a field of tuple type",
+        ));
+    };
+
+    let tys = tuple.elems.iter().collect::<Vec<_>>();
+
+    // there is nothing to unpack into separate parameters otherwise
+    if tys.len() < 2 {
+        return Err(syn::Error::new_spanned(
+            ty,
+            "`unpack` requires a tuple of at least two elements",
+        ));
+    }
+
+    let names = match names {
+        Some(names) if names.len() != tys.len() => {
+            return Err(syn::Error::new_spanned(
+                ty,
+                format!(
+                    "`unpack` expects {} parameter names for this tuple, found {}",
+                    tys.len(),
+                    names.len()
+                ),
+            ));
+        }
+        Some(names) => names.to_vec(),
+        None if tys.len() > 26 => {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "name the parameters of tuples with more than 26 elements with `unpack(...)`",
+            ));
+        }
+        None => (b'a'..)
+            .take(tys.len())
+            .map(|c| format_ident!("{}", c as char))
+            .collect(),
+    };
+
+    Ok((names, tys))
+}
@@ -0,0 +1,80 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote, quote_spanned};
+use syn::{parse_quote, spanned::Spanned, ItemStruct, Visibility};
+
+use crate::{
+    args::Args,
+    field::Field,
+    item::{non_exhaustive, require_named_fields},
+};
+
+/// Generate the `{Struct}Snapshot` struct requested by `#[ters(generate_snapshot)]`, holding
+/// clones of the fields which have getters, along with the `snapshot` method returning one.
+///
+/// Returns the method and the struct separately, as the method belongs in the impl block.
+pub fn expand(
+    item: &ItemStruct,
+    fields: &[Field],
+    args: &Args,
+    span: Span,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    require_named_fields(item, "generate_snapshot", span, false)?;
+
+    let ItemStruct { vis, ident, .. } = item;
+    let snapshot_ident = format_ident!("{ident}Snapshot");
+    let doc = format!(
+        "Clones of the fields of [`{ident}`] which have getters, taken by [`{ident}::snapshot`]."
+    );
+    let method_doc = format!("Clone the fields which have getters into a [`{snapshot_ident}`].");
+    let method_vis: Visibility = args.default_vis().unwrap_or(parse_quote!(pub));
+
+    let fields = fields
+        .iter()
+        .filter(|field| !field.getters.is_empty())
+        .collect::<Vec<_>>();
+
+    let cfgs = fields
+        .iter()
+        .map(|field| field.cfg_attrs())
+        .collect::<Vec<_>>();
+    let docs = fields
+        .iter()
+        .map(|field| field.docs_or(format!("Clone of `{}`.", field.name())));
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let tys = fields.iter().map(|field| &field.ty);
+    let non_exhaustive = non_exhaustive(item);
+
+    let clones = fields.iter().map(|field| {
+        let Field { ident, ty, .. } = field;
+
+        quote_spanned! {ty.span()=> ::core::clone::Clone::clone(&self.#ident) }
+    });
+
+    let method = quote! {
+        #[doc = #method_doc]
+        #[inline]
+        #method_vis fn snapshot(&self) -> #snapshot_ident {
+            #snapshot_ident {
+                #(
+                    #(#cfgs)*
+                    #idents: #clones,
+                )*
+            }
+        }
+    };
+
+    let snapshot = quote! {
+        #[doc = #doc]
+        #[derive(::core::fmt::Debug, ::core::clone::Clone, ::core::cmp::PartialEq)]
+        #non_exhaustive
+        #vis struct #snapshot_ident {
+            #(
+                #(#cfgs)*
+                #(#docs)*
+                #vis #idents: #tys,
+            )*
+        }
+    };
+
+    Ok((method, snapshot))
+}
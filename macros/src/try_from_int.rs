@@ -0,0 +1,68 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{ItemEnum, Type};
+
+/// Generate the `TryFrom` impl requested by `#[ters(try_from_int)]` on a fieldless enum,
+/// mapping integers to the variants with those discriminants, along with its error type.
+pub fn expand(item: &ItemEnum, ty: &Type, span: Span) -> syn::Result<TokenStream> {
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            "`try_from_int` is not supported on generic enums",
+        ));
+    }
+
+    let mut with_fields = item
+        .variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+        .map(|variant| {
+            syn::Error::new_spanned(
+                &variant.fields,
+                "`try_from_int` requires an enum whose variants have no fields",
+            )
+        });
+
+    if let Some(mut e) = with_fields.next() {
+        with_fields.for_each(|other| e.combine(other));
+        return Err(e);
+    }
+
+    let ItemEnum { vis, ident, .. } = item;
+    let error = format_ident!("{ident}TryFromIntError");
+    let doc = format!("The error returned when an integer is not the discriminant of a [`{ident}`] variant, holding the integer.");
+    let message = format!("{{}} is not the discriminant of a `{ident}` variant");
+    let variants = item.variants.iter().map(|variant| &variant.ident);
+
+    // every discriminant fits in an `i128`, and comparing there avoids truncating
+    // either side to a narrower type
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::fmt::Debug, ::core::cmp::PartialEq, ::core::cmp::Eq)]
+        #vis struct #error(pub #ty);
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for #error {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::write!(f, #message, self.0)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::TryFrom<#ty> for #ident {
+            type Error = #error;
+
+            fn try_from(value: #ty) -> ::core::result::Result<Self, Self::Error> {
+                #(
+                    if ::core::result::Result::ok(<#ty as ::core::convert::TryFrom<i128>>::try_from(Self::#variants as i128))
+                        == ::core::option::Option::Some(value)
+                    {
+                        return ::core::result::Result::Ok(Self::#variants);
+                    }
+                )*
+
+                ::core::result::Result::Err(#error(value))
+            }
+        }
+    })
+}
@@ -0,0 +1,75 @@
+use syn::{parse_quote, GenericArgument, PathArguments, Type};
+
+/// The generic type arguments of `ty` if it is written as a path ending in `name`,
+/// e.g. `Result<T, E>` or `core::result::Result<T, E>` for `"Result"`.
+///
+/// Type aliases cannot be seen through, so only the type as written is considered.
+pub fn generic_args<'a>(ty: &'a Type, name: &str) -> Option<Vec<&'a Type>> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    let segment = path.path.segments.last()?;
+
+    if segment.ident != name {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    Some(
+        args.args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// The only generic type argument of `ty` if it is written as a path ending in `name`,
+/// e.g. `T` for `Arc<T>` and `"Arc"`.
+pub fn generic_arg<'a>(ty: &'a Type, name: &str) -> Option<&'a Type> {
+    match generic_args(ty, name)?.as_slice() {
+        [arg] => Some(arg),
+        _ => None,
+    }
+}
+
+/// The value type of `ty` if it is written as a path ending in one of the atomic types,
+/// e.g. `u32` for `AtomicU32` or `*mut T` for `AtomicPtr<T>`.
+pub fn atomic_value(ty: &Type) -> Option<Type> {
+    if let Some(pointee) = generic_arg(ty, "AtomicPtr") {
+        return Some(parse_quote!(*mut #pointee));
+    }
+
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    let segment = path.path.segments.last()?;
+
+    if !segment.arguments.is_none() {
+        return None;
+    }
+
+    let value = match segment.ident.to_string().as_str() {
+        "AtomicBool" => "bool",
+        "AtomicI8" => "i8",
+        "AtomicI16" => "i16",
+        "AtomicI32" => "i32",
+        "AtomicI64" => "i64",
+        "AtomicIsize" => "isize",
+        "AtomicU8" => "u8",
+        "AtomicU16" => "u16",
+        "AtomicU32" => "u32",
+        "AtomicU64" => "u64",
+        "AtomicUsize" => "usize",
+        _ => return None,
+    };
+
+    syn::parse_str(value).ok()
+}
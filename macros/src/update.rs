@@ -0,0 +1,49 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::{parse_quote, spanned::Spanned, Visibility};
+
+use crate::{args::Args, field::Field};
+
+/// Generate the `update` method requested by `#[ters(generate_update_fn)]`, cloning
+/// the fields which have setters from another instance.
+pub fn expand(fields: &[Field], args: &Args, span: Span) -> syn::Result<TokenStream> {
+    let fields = fields
+        .iter()
+        .filter(|field| !field.setters.is_empty())
+        .collect::<Vec<_>>();
+
+    if fields.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            "`generate_update_fn` requires at least one field with a setter",
+        ));
+    }
+
+    let listed = fields
+        .iter()
+        .map(|field| format!("`{}`", field.name()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let doc = format!("Copy the fields which have setters from `other`, cloning them: {listed}.");
+    let vis: Visibility = args.default_vis().unwrap_or(parse_quote!(pub));
+
+    let stores = fields.iter().map(|field| {
+        let Field { member, ty, .. } = field;
+        let cfgs = field.cfg_attrs();
+
+        quote_spanned! {ty.span()=>
+            #(#cfgs)*
+            {
+                self.#member = ::core::clone::Clone::clone(&other.#member);
+            }
+        }
+    });
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[inline]
+        #vis fn update(&mut self, other: &Self) {
+            #(#stores)*
+        }
+    })
+}
@@ -0,0 +1,39 @@
+use syn::{Path, Visibility};
+
+/// How widely an item of a given visibility is accessible, where known.
+///
+/// Visibilities restricted to arbitrary paths are not ordered relative to one another.
+fn reach(vis: &Visibility) -> u8 {
+    match vis {
+        Visibility::Inherited => 0,
+        Visibility::Restricted(restricted) if restricted.path.is_ident("self") => 0,
+        Visibility::Restricted(restricted) if restricted.path.is_ident("crate") => 2,
+        Visibility::Restricted(_) => 1,
+        Visibility::Public(_) => 3,
+    }
+}
+
+/// Whether `vis` is accessible more widely than `than`.
+pub fn is_wider(vis: &Visibility, than: &Visibility) -> bool {
+    reach(vis) > reach(than)
+}
+
+/// Render a visibility as written, e.g. `pub(in crate::foo)`, or `private`.
+pub fn describe(vis: &Visibility) -> String {
+    let path = |path: &Path| {
+        path.segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::")
+    };
+
+    match vis {
+        Visibility::Inherited => "private".into(),
+        Visibility::Public(_) => "pub".into(),
+        Visibility::Restricted(restricted) if restricted.in_token.is_some() => {
+            format!("pub(in {})", path(&restricted.path))
+        }
+        Visibility::Restricted(restricted) => format!("pub({})", path(&restricted.path)),
+    }
+}
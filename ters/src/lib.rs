@@ -1,5 +1,7 @@
 //! Generate getters and setters procedurally.
 //!
+//! Generated code only references `core`, so `ters` can be used on bare-metal targets without `alloc`. Helpers for `std` types are gated behind the `std` feature.
+//!
 //! Annotate fields with `#[get]` to generate a getter method.
 //! ```
 //! use ters::ters;
@@ -71,63 +73,3066 @@
 //!     assert_eq!(foo.a(), &42); // this method doesn't exist
 //! }
 //! ```
-
-#![no_std]
-
-pub use ters_macros::ters;
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn getters() {
-        #[ters]
-        struct Foo {
-            #[allow(unused)]
-            a: i32,
-            #[get]
-            b: bool,
-        }
-
-        let foo = Foo { a: 42, b: true };
-        assert_eq!(foo.b(), &true);
-    }
-
-    #[test]
-    fn setters() {
-        #[ters]
-        struct Foo {
-            #[allow(unused)]
-            a: i32,
-            #[get]
-            #[set]
-            b: bool,
-        }
-
-        let mut foo = Foo { a: 42, b: true };
-        assert_eq!(foo.b(), &true);
-        foo.set_b(false);
-
-        assert_eq!(foo.b(), &false);
-    }
-
-    #[test]
-    fn both() {
-        #[ters]
-        struct Foo {
-            #[get]
-            a: i32,
-            #[get]
-            #[set]
-            b: bool,
-        }
-
-        let mut foo = Foo { a: 42, b: true };
-        assert_eq!(foo.a(), &42);
-        assert_eq!(foo.b(), &true);
-        foo.set_b(false);
-
-        assert_eq!(foo.b(), &false);
+//!
+//! Pass `normalize = path` to `#[set]` to transform values before they are stored.
+//! The function must take and return the field's type.
+//! ```
+//! use ters::ters;
+//!
+//! fn trim(value: &'static str) -> &'static str {
+//!     value.trim()
+//! }
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get]
+//!     #[set(normalize = trim)]
+//!     a: &'static str,
+//! }
+//!
+//! fn normalize() {
+//!     let mut foo = Foo { a: "" };
+//!     foo.set_a("  bar ");
+//!     assert_eq!(foo.a(), &"bar");
+//! }
+//! ```
+//!
+//! Pass `propagate_lints` to `#[ters]` to copy lint attributes (`#[allow]`, `#[expect]`, `#[warn]`, `#[deny]`, and `#[forbid]`) from fields onto their accessors.
+//! An `#[expect]` is copied as an `#[allow]`, since the accessor need not trigger the expected lint itself.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(propagate_lints)]
+//! struct Foo {
+//!     #[get]
+//!     #[allow(clippy::struct_field_names)]
+//!     foo_a: i32,
+//! }
+//! ```
+//!
+//! Generated accessors are implemented on the struct's type, which lives in a separate namespace from values.
+//! Local variables sharing the struct's name do not affect the generated code.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get]
+//!     a: i32,
+//! }
+//!
+//! #[allow(non_snake_case)]
+//! fn shadowed() {
+//!     let Foo = Foo { a: 42 };
+//!     assert_eq!(Foo.a(), &42);
+//! }
+//! ```
+//!
+//! `#[ters]` can only be applied to structs, and to enums with `try_from_int`.
+//! ```compile_fail
+//! use ters::ters;
+//!
+//! #[ters]
+//! enum Foo {
+//!     A,
+//!     B,
+//! }
+//! ```
+//!
+//! Pass `format_with = path` to `#[get]` to generate a `_display` getter which formats the field with the given function.
+//! The function takes a reference to the field and returns something implementing `Display`.
+//! ```
+//! use core::fmt::{self, Display, Formatter};
+//!
+//! use ters::ters;
+//!
+//! struct Celsius<'a>(&'a f32);
+//!
+//! impl Display for Celsius<'_> {
+//!     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+//!         write!(f, "{}°C", self.0)
+//!     }
+//! }
+//!
+//! fn celsius(value: &f32) -> Celsius<'_> {
+//!     Celsius(value)
+//! }
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(format_with = celsius)]
+//!     a: f32,
+//! }
+//!
+//! fn format_with() {
+//!     let foo = Foo { a: 21.5 };
+//!     assert_eq!(foo.a_display().to_string(), "21.5°C");
+//! }
+//! ```
+//!
+//! Generated accessors are `pub`, but remain bounded by the visibility of the struct: they can't be reached from anywhere the struct itself is inaccessible.
+//! ```compile_fail,E0603
+//! mod inner {
+//!     use ters::ters;
+//!
+//!     #[ters]
+//!     pub(super) struct Foo {
+//!         #[get]
+//!         a: i32,
+//!     }
+//! }
+//!
+//! fn accessible(foo: &inner::Foo) -> &i32 {
+//!     foo.a()
+//! }
+//!
+//! mod elsewhere {
+//!     mod inner {
+//!         use ters::ters;
+//!
+//!         #[ters]
+//!         struct Foo {
+//!             #[get]
+//!             a: i32,
+//!         }
+//!     }
+//!
+//!     fn inaccessible(foo: &inner::Foo) -> &i32 {
+//!         foo.a() // `Foo` is private
+//!     }
+//! }
+//! ```
+//!
+//! Multiple `#[ters]` applications on one struct are merged, so options can be enabled conditionally with `#[cfg_attr]` while still generating a single impl block.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! #[cfg_attr(feature = "lints", ters(propagate_lints))]
+//! struct Foo {
+//!     #[get]
+//!     a: i32,
+//! }
+//! ```
+//!
+//! Pass `on_read = hook` to `#[get]` to call a function whenever the getter is invoked.
+//! Closures taking an argument receive the field name as a `&'static str`.
+//! ```
+//! use ters::ters;
+//!
+//! fn log_access() {}
+//!
+//! fn record_access(_name: &'static str) {}
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(on_read = log_access)]
+//!     a: i32,
+//!     #[get(on_read = |name| record_access(name))]
+//!     b: bool,
+//! }
+//! ```
+//!
+//! Pass `name = "..."` to `#[get]` or `#[set]` to override the name of the generated method.
+//! Names must be valid identifiers; keywords can be used through raw identifiers (`"r#type"`).
+//! Leading and trailing underscores and non-ASCII identifiers are accepted just as rustc accepts them, e.g. `"_len"` or `"größe"`.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(name = "value")]
+//!     #[set(name = "replace")]
+//!     a: i32,
+//! }
+//!
+//! fn rename() {
+//!     let mut foo = Foo { a: 42 };
+//!     foo.replace(31);
+//!     assert_eq!(foo.value(), &31);
+//! }
+//! ```
+//!
+//! Pass `builder` to `#[set]` to generate a `with_` setter which takes and returns `self` for chaining.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get]
+//!     #[set(builder)]
+//!     a: i32,
+//!     #[get]
+//!     #[set(builder)]
+//!     b: bool,
+//! }
+//!
+//! fn builder() {
+//!     let foo = Foo { a: 0, b: false }.with_a(42).with_b(true);
+//!     assert_eq!(foo.a(), &42);
+//!     assert_eq!(foo.b(), &true);
+//! }
+//! ```
+//!
+//! When combined with `pin_project`, place `#[ters]` above `#[pin_project]`.
+//! `#[pin]` attributes are left in place for `pin_project`, and requesting accessors for pinned fields is an error since they are accessed through projections instead.
+//!
+//! Pass `copy` or `clone` to `#[get]` to return the field by value rather than by reference.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(copy)]
+//!     a: i32,
+//!     #[get(clone)]
+//!     b: String,
+//! }
+//!
+//! fn by_value() {
+//!     let foo = Foo { a: 42, b: "bar".into() };
+//!     let a: i32 = foo.a();
+//!     let b: String = foo.b();
+//! }
+//! ```
+//!
+//! The field's type must implement the corresponding trait, which is reported against the field.
+//! ```compile_fail,E0277
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(copy)]
+//!     a: String, // `String` is not `Copy`
+//! }
+//! ```
+//!
+//! Getters are `pub`, so a field of a private type leaks that type through the public interface (which rustc reports via the `private_interfaces` lint); choosing accessors appropriate for the field's type is the user's responsibility.
+//! Pass `opaque` to `#[get]` to return the field as `&dyn Any` instead, hiding its concrete type while still allowing downcasting.
+//! ```
+//! use core::any::Any;
+//!
+//! use ters::ters;
+//!
+//! struct Secret(i32);
+//!
+//! #[ters]
+//! pub struct Foo {
+//!     #[get(opaque)]
+//!     a: Secret,
+//! }
+//!
+//! fn opaque(foo: &Foo) {
+//!     let any: &dyn Any = foo.a();
+//!     assert!(any.downcast_ref::<Secret>().is_some());
+//! }
+//! ```
+//!
+//! Pass `matches` with a pattern to `#[get]` to generate a predicate testing the field against it with `core::matches!`.
+//! A `name` is required, and a field may have several predicates.
+//! ```
+//! use ters::ters;
+//!
+//! enum State {
+//!     Idle,
+//!     Connected { peer: u16 },
+//!     Closed,
+//! }
+//!
+//! #[ters]
+//! struct Conn {
+//!     #[get(matches = State::Connected { .. }, name = "is_connected")]
+//!     #[get(matches = State::Idle | State::Closed, name = "is_inactive")]
+//!     state: State,
+//! }
+//!
+//! fn matches(conn: &Conn) {
+//!     let connected: bool = conn.is_connected();
+//!     let inactive: bool = conn.is_inactive();
+//! }
+//! ```
+//!
+//! Pass `sized` to `#[get]` to bound the generated getter by `where Self: Sized`, so that it is only available on sized instantiations of the struct.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo<T: ?Sized> {
+//!     #[get(sized)]
+//!     a: i32,
+//!     b: T,
+//! }
+//!
+//! fn sized(foo: &Foo<[u8; 2]>) {
+//!     let a: &i32 = foo.a();
+//! }
+//! ```
+//!
+//! Pass `info` to `#[ters]` to list the methods it generates in a compile-time warning, which is handy when inspecting the expansion from an IDE or build log.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(info)] // warning: `#[ters]` generates these methods for `Foo`: `a`, `set_a`
+//! struct Foo {
+//!     #[get]
+//!     #[set]
+//!     a: i32,
+//! }
+//! ```
+//!
+//! Pass `ok`, `err`, or `is_ok` to `#[get]` on a `Result<T, E>` field to generate `_ok` and `_err` getters returning `Option<&T>` and `Option<&E>`, or an `_is_ok` predicate.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(ok)]
+//!     #[get(err)]
+//!     #[get(is_ok)]
+//!     parse: Result<i32, String>,
+//! }
+//!
+//! fn result(foo: &Foo) {
+//!     let ok: Option<&i32> = foo.parse_ok();
+//!     let err: Option<&String> = foo.parse_err();
+//!     let is_ok: bool = foo.parse_is_ok();
+//! }
+//! ```
+//!
+//! The field's type must be written as `Result<T, E>`, since type aliases cannot be seen through by the macro.
+//! ```compile_fail
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(ok)]
+//!     a: Option<i32>, // not a `Result`
+//! }
+//! ```
+//!
+//! Pass `unpack` to `#[set]` on a field holding a tuple of two or more elements to take its elements as separate parameters, named `a`, `b`, and so on unless names are given as `unpack(x, y)`.
+//! Combined with `#[get(copy)]`, tuples of `Copy` elements can be read back by value.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(copy)]
+//!     #[set(unpack)]
+//!     pos: (f32, f32),
+//!     #[set(unpack(start, end))]
+//!     range: (u32, u32),
+//! }
+//!
+//! fn unpack(foo: &mut Foo) {
+//!     foo.set_pos(1.0, 2.0);
+//!     foo.set_range(0, 10);
+//!     let (x, y) = foo.pos();
+//! }
+//! ```
+//!
+//! With the `async` feature enabled, pass `async_fn` to `#[get]` to generate an `async fn` getter for APIs expecting all accessors to be awaited.
+#![cfg_attr(feature = "async", doc = "```")]
+#![cfg_attr(not(feature = "async"), doc = "```ignore")]
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(async_fn)]
+//!     a: i32,
+//! }
+//!
+//! async fn async_fn(foo: &Foo) {
+//!     let a: &i32 = foo.a().await;
+//! }
+//! ```
+//!
+//! Pass `constructor` to `#[ters]` to generate a `new` constructor taking every field.
+//! With `skip_none` as well, `Option` fields are left out and initialized to `None`, unless marked `#[include_in_constructor]`.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(constructor, skip_none)]
+//! struct Foo {
+//!     a: i32,
+//!     b: Option<i32>,
+//!     #[include_in_constructor]
+//!     c: Option<i32>,
+//! }
+//!
+//! fn constructor() -> Foo {
+//!     Foo::new(1, Some(3))
+//! }
+//! ```
+//!
+//! Pass `bounded` with a range to `#[set]` to debug-assert that values lie within it; unlike clamping, release builds store the value unchecked.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[set(bounded = "0.0..=1.0")]
+//!     opacity: f32,
+//! }
+//!
+//! fn bounded(foo: &mut Foo) {
+//!     foo.set_opacity(0.5);
+//! }
+//! ```
+//!
+//! For tooling that needs to know which accessors exist, set the `TERS_DUMP_METHODS` environment variable to a directory while building.
+//! Each `#[ters]` struct then writes its generated methods to `{dir}/{Struct}.json`, and each `#[derive(Getters)]` or `#[derive(Setters)]` to `{dir}/{Struct}.Getters.json` or `{dir}/{Struct}.Setters.json`, in the form `{"struct":"Foo","methods":[{"name":"a","signature":"pub fn a(&self) -> &i32"}]}`.
+//! Structs of the same name overwrite each other's listings, and since rustc does not track the variable, changing it requires a rebuild of the crates involved.
+//!
+//! Pass `vis` to `#[get]` or `#[set]` to override the `pub` visibility of the generated method, e.g. `vis = "pub(crate)"`, or `vis = ""` for a private one. The visibility may also be written bare, as in `vis = pub(crate)`.
+//! As with any method, the visibility is relative to the module defining the struct, and re-exporting the struct elsewhere does not widen it.
+//! ```compile_fail,E0624
+//! mod inner {
+//!     mod nested {
+//!         use ters::ters;
+//!
+//!         #[ters]
+//!         pub struct Foo {
+//!             #[get(vis = "pub(super)")]
+//!             a: i32,
+//!         }
+//!
+//!         impl Foo {
+//!             pub fn new() -> Self {
+//!                 Self { a: 42 }
+//!             }
+//!         }
+//!     }
+//!
+//!     pub use nested::Foo;
+//! }
+//!
+//! fn reexported() {
+//!     let foo = inner::Foo::new();
+//!     let a = foo.a(); // only visible within `inner`
+//! }
+//! ```
+//!
+//! Search aliases declared with `#[doc(alias = "...")]` on a field are forwarded to its accessors, prefixed with `get_` or `set_`.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[doc(alias = "colour")]
+//!     #[get] // searchable as `get_colour`
+//!     #[set] // searchable as `set_colour`
+//!     color: u32,
+//! }
+//! ```
+//!
+//! With the `std` feature enabled, pass `arc_ptr` to `#[get]` on an `Arc<T>` field to generate a `_ptr` getter returning the `*const T` to the data it manages.
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! use std::sync::Arc;
+//!
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(arc_ptr)]
+//!     a: Arc<i32>,
+//! }
+//!
+//! fn arc_ptr(foo: &Foo) {
+//!     let ptr: *const i32 = foo.a_ptr();
+//! }
+//! ```
+//!
+//! Pass `discriminant` to `#[get]` on an enum field to generate a `_discriminant` getter returning its `core::mem::Discriminant`, for comparisons ignoring payload data.
+//! ```
+//! use core::mem::Discriminant;
+//!
+//! use ters::ters;
+//!
+//! enum State {
+//!     Idle,
+//!     Busy(u8),
+//! }
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(discriminant)]
+//!     state: State,
+//! }
+//!
+//! fn discriminant(foo: &Foo) {
+//!     let discriminant: Discriminant<State> = foo.state_discriminant();
+//! }
+//! ```
+//!
+//! Since a method is never more accessible than its type, a `pub` getter on a `pub(super)` struct is effectively `pub(super)`.
+//! Pass `visibility_check` to `#[ters]` to reject accessors declared more visible than the struct, so that their declared visibility is never misleading.
+//! ```compile_fail
+//! mod inner {
+//!     use ters::ters;
+//!
+//!     #[ters(visibility_check)]
+//!     pub(super) struct Foo {
+//!         #[get] // `pub`, but `Foo` is only `pub(super)`
+//!         a: i32,
+//!     }
+//! }
+//! ```
+//!
+//! With the `std` feature enabled, pass `push_back` or `push_front` to `#[set]` on a `VecDeque<T>` field to generate a setter pushing elements onto that end, prefixed `push_back_` or `push_front_`.
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! use std::collections::VecDeque;
+//!
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[set(push_back)]
+//!     #[set(push_front)]
+//!     queue: VecDeque<i32>,
+//! }
+//!
+//! fn push(foo: &mut Foo) {
+//!     foo.push_back_queue(2);
+//!     foo.push_front_queue(1);
+//! }
+//! ```
+//!
+//! Likewise, pass `pop_front` or `pop_back` to `#[get]` on a `VecDeque<T>` field to generate a getter popping elements off that end, prefixed `pop_front_` or `pop_back_`.
+//! These take `&mut self`, since popping modifies the field.
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! use std::collections::VecDeque;
+//!
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(pop_front)]
+//!     #[get(pop_back)]
+//!     queue: VecDeque<i32>,
+//! }
+//!
+//! fn pop(foo: &mut Foo) {
+//!     let front: Option<i32> = foo.pop_front_queue();
+//!     let back: Option<i32> = foo.pop_back_queue();
+//! }
+//! ```
+//!
+//! Pass `transmute_to` with a type to `#[get]` to generate an `unsafe` getter reinterpreting the field's bits as that type, named `{field}_as_{type}` unless a `name` is given.
+//! The field must be `Copy`, and the sizes of both types must match.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(transmute_to = f32)]
+//!     bits: u32,
+//! }
+//!
+//! fn transmute_to(foo: &Foo) {
+//!     // SAFETY: every bit pattern is a valid `f32`
+//!     let value: f32 = unsafe { foo.bits_as_f32() };
+//! }
+//! ```
+//! ```compile_fail,E0080
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(transmute_to = f64)] // `u32` and `f64` differ in size
+//!     bits: u32,
+//! }
+//! ```
+//!
+//! Pass `passthrough` to `#[ters]` to generate nothing at all, re-emitting the struct with just the accessor attributes removed.
+//! This is useful when `#[ters]` is only wanted in some configurations.
+//! ```
+//! use ters::ters;
+//!
+//! #[cfg_attr(feature = "accessors", ters)]
+//! #[cfg_attr(not(feature = "accessors"), ters(passthrough))]
+//! struct Foo {
+//!     #[get]
+//!     a: i32,
+//! }
+//! ```
+//!
+//! Pass `read_only` to `#[get]` to declare that a field is intentionally not settable through accessors; adding a `#[set]` to it is then an error.
+//! `#[ters(set)]` leaves such fields out instead.
+//! ```compile_fail
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(read_only)]
+//!     #[set] // conflicts with `read_only`
+//!     a: i32,
+//! }
+//! ```
+//!
+//! Pass `into_fields` to `#[ters]` to generate a `{Struct}Fields` struct holding the fields which have getters, along with a `From` impl moving them out of the struct.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(into_fields)]
+//! pub struct Foo {
+//!     #[get]
+//!     a: i32,
+//!     b: i32,
+//!     #[get]
+//!     c: String,
+//! }
+//!
+//! fn into_fields(foo: Foo) {
+//!     let FooFields { a, c } = foo.into();
+//! }
+//! ```
+//!
+//! If the struct is `#[must_use]`, so are its getters, while setters are left as they are.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! #[must_use]
+//! struct Foo {
+//!     #[get] // `#[must_use]`
+//!     #[set]
+//!     a: i32,
+//! }
+//! ```
+//!
+//! With the `log` feature enabled, pass `log_changes` to `#[set]` to log the old and new values with `log::debug!` whenever the setter is called.
+//! The feature pulls in the `log` crate for the generated code, and the field's type must implement `Debug`.
+#![cfg_attr(feature = "log", doc = "```")]
+#![cfg_attr(not(feature = "log"), doc = "```ignore")]
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[set(log_changes)]
+//!     a: i32,
+//! }
+//!
+//! fn log_changes(foo: &mut Foo) {
+//!     foo.set_a(42); // logs "a: 0 -> 42"
+//! }
+//! ```
+//!
+//! Pass `hash_value` to `#[get]` to generate a `_hash` getter returning the field's hash as a `u64`, which is useful for detecting changes without keeping the old value.
+//! The hash is computed with `DefaultHasher`, which requires the `std` feature, or with the `Default` hasher given as `hash_value = MyHasher`.
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(hash_value)]
+//!     a: String,
+//! }
+//!
+//! fn hash_value(foo: &Foo) {
+//!     let hash: u64 = foo.a_hash();
+//! }
+//! ```
+//!
+//! Generic structs, including ones with const generic parameters and defaults, have their accessors generated for every instantiation.
+//! Rust has no structs nested in `impl` blocks, and structs nested in generic functions cannot use the function's parameters, so the struct's own parameters are the only ones to consider.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo<const N: usize = 4> {
+//!     #[get]
+//!     a: [u8; N],
+//! }
+//!
+//! fn const_generics(foo: &Foo<2>) {
+//!     let a: &[u8; 2] = foo.a();
+//! }
+//! ```
+//!
+//! Generic structs, including ones with const generic parameters and defaults, have their accessors generated for every instantiation.
+//! Rust has no structs nested in `impl` blocks, and structs nested in generic functions cannot use the function's parameters, so the struct's own parameters are the only ones to consider.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo<const N: usize = 4> {
+//!     #[get]
+//!     a: [u8; N],
+//! }
+//!
+//! fn const_generics(foo: &Foo<2>) {
+//!     let a: &[u8; 2] = foo.a();
+//! }
+//! ```
+//!
+//! Getters and setters can also be generated with derive macros, which leave the struct untouched. Struct-level `#[ters(...)]` options are not available this way.
+//!
+//! ```
+//! use ters::{Getters, Setters};
+//!
+//! #[derive(Getters, Setters)]
+//! struct Foo {
+//!     #[get]
+//!     #[set]
+//!     a: i32,
+//! }
+//! ```
+//!
+//! Inherent methods take precedence over trait methods, so a getter named like a standard trait method, such as `clone`, silently changes what `foo.clone()` calls.
+//! Pass `conflict_check` to `#[ters]` to reject accessors shadowing methods of standard traits.
+//! ```compile_fail
+//! use ters::ters;
+//!
+//! #[ters(conflict_check)]
+//! #[derive(Clone)]
+//! struct Foo {
+//!     #[get(name = "clone")] // shadows `Clone::clone`
+//!     a: i32,
+//! }
+//! ```
+//!
+//! Pass `mut` to `#[get]` to generate a `_mut` getter returning a mutable reference to the field.
+//! To generate both the shared and mutable getters with one annotation, pass `mutable_alias` (or its synonyms `with_mut` and `mut_pair`) instead.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(mut)]
+//!     a: i32,
+//!     #[get(mutable_alias)] // `b` and `b_mut`
+//!     b: i32,
+//! }
+//!
+//! fn mutable(foo: &mut Foo) {
+//!     *foo.a_mut() += 1;
+//!     *foo.b_mut() = *foo.b() + 1;
+//! }
+//! ```
+//!
+//! Pass `conditional` to `#[set]` to only store values satisfying a predicate, which is given a reference to the new value.
+//! The setter returns whether the value was stored.
+//! ```
+//! use ters::ters;
+//!
+//! fn is_even(value: &i32) -> bool {
+//!     value % 2 == 0
+//! }
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[set(conditional = is_even)]
+//!     a: i32,
+//! }
+//!
+//! fn conditional(foo: &mut Foo) {
+//!     assert!(foo.set_a(2));
+//!     assert!(!foo.set_a(3));
+//! }
+//! ```
+//!
+//! Crates re-exporting the attribute, such as with `pub use ters::ters;`, can be used just the same, since macros are resolved by what they are rather than by their path.
+//! Further applications are merged by name, so keep the re-export named `ters`.
+//! ```
+//! mod reexport {
+//!     pub use ters::ters;
+//! }
+//!
+//! #[reexport::ters]
+//! #[reexport::ters(constructor)]
+//! struct Foo {
+//!     #[get]
+//!     a: i32,
+//! }
+//!
+//! fn reexported() {
+//!     let foo = Foo::new(1);
+//!     assert_eq!(foo.a(), &1);
+//! }
+//! ```
+//!
+//! Pass `idempotent` to `#[set]` to skip storing values equal to the current one, avoiding spurious updates in observer patterns.
+//! The setter returns whether the value changed, and requires the field to implement `PartialEq`.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[set(idempotent)]
+//!     a: i32,
+//! }
+//!
+//! fn idempotent(foo: &mut Foo) {
+//!     assert!(foo.set_a(1));
+//!     assert!(!foo.set_a(1));
+//! }
+//! ```
+//!
+//! With the `std` feature enabled, pass `debug_str` to `#[get]` to generate a `_debug_str` getter returning the field formatted with `{:?}` as a `String`, for logging and diagnostics.
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get(debug_str)]
+//!     a: Option<i32>,
+//! }
+//!
+//! fn debug_str(foo: &Foo) {
+//!     let debug: String = foo.a_debug_str();
+//! }
+//! ```
+//!
+//! Structs from other crates cannot be annotated, but can be wrapped in a newtype which is.
+//! Accessors are only generated for the wrapper's own fields, since the macro cannot see the methods (or private fields) of the wrapped type; forward to the wrapped type by hand where needed.
+//! ```
+//! use core::time::Duration;
+//!
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Timeout {
+//!     #[get(copy)]
+//!     #[set]
+//!     duration: Duration,
+//! }
+//!
+//! impl Timeout {
+//!     fn as_millis(&self) -> u128 {
+//!         self.duration.as_millis()
+//!     }
+//! }
+//!
+//! fn newtype(timeout: &mut Timeout) {
+//!     timeout.set_duration(Duration::from_secs(1));
+//!     assert_eq!(timeout.as_millis(), 1000);
+//! }
+//! ```
+//!
+//! Pass `version = 2` to `#[ters]` to opt into changed defaults without waiting for a breaking release; the current defaults are `version = 1`.
+//! When upgrading to `version = 2`, note that generated methods, including the constructor, are private unless given a `vis`.
+//! ```
+//! mod inner {
+//!     use ters::ters;
+//!
+//!     #[ters(version = 2)]
+//!     pub struct Foo {
+//!         #[get] // private
+//!         a: i32,
+//!         #[get(vis = "pub")]
+//!         b: i32,
+//!     }
+//! }
+//! ```
+//!
+//! Pass `field_name_str` to `#[get]` to generate an `_as_str` getter returning the field's name as a `&'static str`, for reflection-like uses needing the name alongside the value.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Foo {
+//!     #[get]
+//!     #[get(field_name_str)]
+//!     a: i32,
+//! }
+//!
+//! fn field_name_str(foo: &Foo) {
+//!     assert_eq!(foo.a_as_str(), "a");
+//! }
+//! ```
+//!
+//! Pass `setter_prefix` to `#[ters]` to replace the `set_` prefix of setter names.
+//! An empty prefix names setters after their fields alone, which is rejected for fields which also have a getter of that name.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(setter_prefix = "put_")]
+//! struct Foo {
+//!     #[set]
+//!     a: i32,
+//! }
+//!
+//! fn setter_prefix(foo: &mut Foo) {
+//!     foo.put_a(1);
+//! }
+//! ```
+//!
+//! With the `ffi` feature enabled, pass `fn_ptr` to `#[set]` on a `Copy` field to generate a `_SETTER` constant holding an `unsafe extern "C" fn(*mut Foo, T)`, for building C function tables.
+//! It stores its argument into the field of the struct pointed to, which must be valid for writes.
+#![cfg_attr(feature = "ffi", doc = "```")]
+#![cfg_attr(not(feature = "ffi"), doc = "```ignore")]
+//! use ters::ters;
+//!
+//! #[ters]
+//! #[repr(C)]
+//! struct Foo {
+//!     #[set(fn_ptr)]
+//!     a: i32,
+//! }
+//!
+//! fn fn_ptr(foo: &mut Foo) {
+//!     let set: unsafe extern "C" fn(*mut Foo, i32) = Foo::A_SETTER;
+//!     unsafe { set(foo, 1) };
+//! }
+//! ```
+//!
+//! Likewise, pass `fn_ptr` to `#[get]` on a `Copy` field to generate a `_GETTER` constant holding an `unsafe extern "C" fn(*const Foo) -> T`, returning a copy of the field of the struct pointed to.
+//! Together, these build C-compatible function tables purely from annotations.
+#![cfg_attr(feature = "ffi", doc = "```")]
+#![cfg_attr(not(feature = "ffi"), doc = "```ignore")]
+//! use ters::ters;
+//!
+//! #[ters]
+//! #[repr(C)]
+//! struct Foo {
+//!     #[get(fn_ptr)]
+//!     #[set(fn_ptr)]
+//!     a: i32,
+//! }
+//!
+//! #[repr(C)]
+//! struct VTable {
+//!     get: unsafe extern "C" fn(*const Foo) -> i32,
+//!     set: unsafe extern "C" fn(*mut Foo, i32),
+//! }
+//!
+//! const VTABLE: VTable = VTable {
+//!     get: Foo::A_GETTER,
+//!     set: Foo::A_SETTER,
+//! };
+//! ```
+//!
+//! Instead of annotating fields, list the fields to generate accessors for with `get = [...]` and `set = [...]` in `#[ters]`, which is more compact when only a few fields of a large struct need them.
+//! Listed fields are treated as if annotated with a plain `#[get]` or `#[set]`.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(get = [name, age], set = [age])]
+//! struct Person {
+//!     name: String,
+//!     age: u8,
+//!     id: u32,
+//! }
+//!
+//! fn field_lists(person: &mut Person) {
+//!     person.set_age(*person.age() + 1);
+//! }
+//! ```
+//!
+//! Pass `get` or `set` to `#[ters]` without a field list to generate accessors for every field not annotated already, leaving out the fields listed in `exclude = [...]`. This needs named fields, so it is rejected on tuple structs.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(get, set, exclude = [cache])]
+//! struct Foo {
+//!     #[get(copy)] // instead of the plain `#[get]`
+//!     a: i32,
+//!     b: bool,
+//!     cache: Option<u64>,
+//! }
+//!
+//! fn all_fields(foo: &mut Foo) {
+//!     foo.set_b(foo.a() > 0);
+//! }
+//! ```
+//!
+//! Pass `zip_adjacent` to `#[get]` to generate a getter returning references to the field and the next one in declaration order, named by concatenating their names.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Vector {
+//!     #[get(zip_adjacent)]
+//!     x: f64,
+//!     y: f64,
+//!     z: f64,
+//! }
+//!
+//! fn zip_adjacent(v: &Vector) {
+//!     let (x, y): (&f64, &f64) = v.xy();
+//! }
+//! ```
+//!
+//! Pass `generate_builder_fn` to `#[ters]` to generate the conventional `builder` entry point, returning the `Default` value of a `FooBuilder` type defined alongside the struct.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(generate_builder_fn)]
+//! struct Foo {
+//!     a: i32,
+//! }
+//!
+//! #[derive(Default)]
+//! struct FooBuilder {
+//!     a: i32,
+//! }
+//!
+//! impl FooBuilder {
+//!     fn build(self) -> Foo {
+//!         Foo { a: self.a }
+//!     }
+//! }
+//!
+//! fn builder() -> Foo {
+//!     Foo::builder().build()
+//! }
+//! ```
+//!
+//! Pass `impl_display` to `#[ters]` to implement `Display`, showing the fields which have getters like `Debug` does, but with their `Display` representations.
+//! The types of those fields must implement `Display`.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(impl_display)]
+//! struct Person {
+//!     #[get]
+//!     name: String,
+//!     #[get]
+//!     age: u8,
+//! }
+//!
+//! fn impl_display(person: &Person) {
+//!     println!("{person}"); // Person { name: Ferris, age: 8 }
+//! }
+//! ```
+//!
+//! Fields which are already `pub` get accessors all the same, for consistency and for method syntax where direct field access would also work.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! pub struct Foo {
+//!     #[get]
+//!     pub a: u32,
+//! }
+//!
+//! fn pub_fields(foo: &Foo) {
+//!     assert_eq!(foo.a(), &foo.a);
+//! }
+//! ```
+//!
+//! Pass `atomic_fetch_update` to `#[set]` on a field of an atomic type to generate a `fetch_update_` method wrapping its `fetch_update`, which updates the value with a closure through a shared reference.
+//! ```
+//! use core::sync::atomic::{AtomicU32, Ordering};
+//!
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Counter {
+//!     #[set(atomic_fetch_update)]
+//!     count: AtomicU32,
+//! }
+//!
+//! fn atomic_fetch_update(counter: &Counter) {
+//!     let previous = counter.fetch_update_count(Ordering::SeqCst, Ordering::SeqCst, |count| count.checked_add(1));
+//! }
+//! ```
+//!
+//! Pass `const_value` to `#[get]` to generate an associated `const fn` returning the given constant expression instead of reading the field, such as for fields mirroring a const generic parameter.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct SizedBuffer<const N: usize> {
+//!     #[get(const_value = N)]
+//!     len: usize,
+//! }
+//!
+//! const LEN: usize = SizedBuffer::<4>::len();
+//! ```
+//!
+//! Other macros can include accessor generation as one step by emitting `#[ters::ters]` on the structs they generate.
+//! The generation logic itself is not exposed as a library function, since proc-macro crates can only export macros.
+//! ```
+//! macro_rules! record {
+//!     ($name:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+//!         #[ters::ters(get)]
+//!         struct $name {
+//!             $($field: $ty),*
+//!         }
+//!     };
+//! }
+//!
+//! record!(Point { x: f32, y: f32 });
+//!
+//! fn generated(point: &Point) -> f32 {
+//!     point.x() + point.y()
+//! }
+//! ```
+//!
+//! Pass `impl_from_tuple` to `#[ters]` on a tuple struct to implement `From` both from and into a tuple of its fields, in order. Accessors of tuple struct fields must be given a `name`.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(impl_from_tuple)]
+//! struct Point(#[get(copy, name = "x")] f32, #[get(copy, name = "y")] f32);
+//!
+//! fn impl_from_tuple() {
+//!     let point = Point::from((1.0, 2.0));
+//!     assert_eq!(point.x(), 1.0);
+//!
+//!     let (x, y) = point.into();
+//!     assert_eq!((x, y), (1.0, 2.0));
+//! }
+//! ```
+//!
+//! Options to `#[ters]` may be combined freely and split across lines. Pass `vis` to set the visibility of accessors not given their own, written as for `#[get]` and `#[set]`, and `rename_all` to convert the generated method names to `camelCase` or `PascalCase` (or keep `snake_case`), except those given a `name`.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(
+//!     get,
+//!     set,
+//!     vis = "pub(crate)",
+//!     setter_prefix = "with_",
+//!     rename_all = "camelCase",
+//! )]
+//! struct Foo {
+//!     bar_baz: u32,
+//! }
+//!
+//! fn rename_all(mut foo: Foo) {
+//!     foo.withBarBaz(42);
+//!     assert_eq!(foo.barBaz(), &42);
+//! }
+//! ```
+//!
+//! Pass `phantom_skip` alongside `get` or `set` to leave out the fields of type `PhantomData<_>`, which hold nothing worth accessing.
+//! ```
+//! use core::marker::PhantomData;
+//!
+//! use ters::ters;
+//!
+//! #[ters(get, phantom_skip)]
+//! struct Id<T> {
+//!     value: u64,
+//!     _marker: PhantomData<T>,
+//! }
+//!
+//! fn phantom_skip(id: &Id<String>) {
+//!     println!("{}", id.value());
+//! }
+//! ```
+//!
+//! Pass `debug_assert_some` to `#[get]` on an `Option<T>` field to return `Option<&T>`, debug-asserting that the field is `Some`. This catches fields read before they are initialized in debug builds, at no cost in release builds.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! struct Connection {
+//!     #[get(debug_assert_some)]
+//!     session: Option<u64>,
+//! }
+//!
+//! fn debug_assert_some(connection: &Connection) {
+//!     // panics in debug builds if `session` is `None`
+//!     println!("{:?}", connection.session());
+//! }
+//! ```
+//!
+//! Pass `generate_index` to `#[ters]` to generate a `{Struct}Field` enum with a variant for each field which has a getter, along with an `Index` impl selecting the field by variant. Since `Index` has a single `Output`, the fields must share a type.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(generate_index)]
+//! struct Rgb {
+//!     #[get]
+//!     red: u8,
+//!     #[get]
+//!     green: u8,
+//!     #[get]
+//!     blue: u8,
+//! }
+//!
+//! fn generate_index(rgb: &Rgb) {
+//!     for channel in [RgbField::Red, RgbField::Green, RgbField::Blue] {
+//!         println!("{channel:?}: {}", rgb[channel]);
+//!     }
+//! }
+//! ```
+//!
+//! Pass `generate_mask` to `#[ters]` on a struct of up to 8 `bool` fields to generate `to_u8` and `from_u8`, packing the fields into bits in declaration order, least significant bit first. Fields removed by `#[cfg]` keep their bits, which are then left unused.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(generate_mask)]
+//! struct Flags {
+//!     #[get(copy)]
+//!     read: bool,
+//!     #[get(copy)]
+//!     write: bool,
+//!     #[get(copy)]
+//!     exec: bool,
+//! }
+//!
+//! fn generate_mask() {
+//!     let flags = Flags::from_u8(0b101);
+//!     assert!(flags.read() && !flags.write() && flags.exec());
+//!     assert_eq!(flags.to_u8(), 0b101);
+//! }
+//! ```
+//!
+//! Pass `try_from_int` to `#[ters]` on an enum whose variants have no fields to implement `TryFrom<u32>`, mapping integers to the variants with those discriminants. Integers which are not a discriminant are rejected with a generated `{Enum}TryFromIntError` holding them. Use `try_from_int = u8` and so on for another integer type.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(try_from_int)]
+//! #[derive(Debug, PartialEq)]
+//! enum Opcode {
+//!     Nop,
+//!     Load = 4,
+//!     Store,
+//! }
+//!
+//! fn try_from_int() {
+//!     assert_eq!(Opcode::try_from(5), Ok(Opcode::Store));
+//!     assert_eq!(Opcode::try_from(1), Err(OpcodeTryFromIntError(1)));
+//! }
+//! ```
+//!
+//! The types generated for `into_fields` and `generate_index` name the fields, so on a `#[non_exhaustive]` struct they are `#[non_exhaustive]` too. Adding a field then cannot break code downstream which matches on them exhaustively.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(into_fields)]
+//! #[non_exhaustive]
+//! pub struct Config {
+//!     #[get]
+//!     retries: u32,
+//! }
+//!
+//! fn non_exhaustive_fields(config: Config) {
+//!     // other crates must write `ConfigFields { retries, .. }`
+//!     let ConfigFields { retries, .. } = config.into();
+//!     println!("{retries}");
+//! }
+//! ```
+//!
+//! Attributes on the struct are left in place for other macros, so `#[ters]` composes with derives such as `thiserror::Error`, whose `#[error]` attribute is preserved.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters]
+//! #[derive(Debug, thiserror::Error)]
+//! #[error("unexpected input at {position}")]
+//! struct ParseError {
+//!     #[get]
+//!     input: String,
+//!     #[get(copy)]
+//!     position: usize,
+//! }
+//!
+//! fn thiserror(e: &ParseError) {
+//!     println!("{e} in {}", e.input());
+//! }
+//! ```
+//!
+//! Pass `generate_update_fn` to `#[ters]` to generate `update`, which copies the fields which have setters from another instance by cloning them. The other fields are left alone.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(generate_update_fn)]
+//! struct Profile {
+//!     #[set]
+//!     name: String,
+//!     #[set]
+//!     age: u8,
+//!     id: u32,
+//! }
+//!
+//! fn generate_update_fn(profile: &mut Profile, edited: &Profile) {
+//!     // `id` is kept
+//!     profile.update(edited);
+//! }
+//! ```
+//!
+//! Pass `no_docs` to `#[ters]` to leave out the `Getter for` and `Setter for` headings of the accessors' documentation. Documentation on the fields is still forwarded.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(no_docs)]
+//! struct Foo {
+//!     /// The number of retries, documented on the getter as is.
+//!     #[get]
+//!     retries: u32,
+//! }
+//!
+//! fn no_docs(foo: &Foo) {
+//!     println!("{}", foo.retries());
+//! }
+//! ```
+//!
+//! Pass `generate_snapshot` to `#[ters]` to generate a `{Struct}Snapshot` struct holding clones of the fields which have getters, along with a `snapshot` method taking one. Snapshots implement `Debug`, `Clone`, and `PartialEq`, so state can be captured and compared later.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(generate_snapshot)]
+//! struct Inventory {
+//!     #[get]
+//!     items: Vec<String>,
+//!     #[get]
+//!     #[set]
+//!     capacity: usize,
+//! }
+//!
+//! fn generate_snapshot(inventory: &mut Inventory) {
+//!     let before = inventory.snapshot();
+//!     inventory.set_capacity(8);
+//!
+//!     assert_ne!(inventory.snapshot(), before);
+//! }
+//! ```
+//!
+//! Pass `generate_diff` to `#[ters]` to generate a `{Struct}Diff` struct with an `Option` for each field which has a getter, along with a `diff` method. Each `Option` holds a clone of the other value if the field differs, and `None` otherwise.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(generate_diff)]
+//! struct Config {
+//!     #[get]
+//!     host: String,
+//!     #[get]
+//!     port: u16,
+//! }
+//!
+//! fn generate_diff(old: &Config, new: &Config) {
+//!     let diff = old.diff(new);
+//!
+//!     if let Some(port) = diff.port {
+//!         println!("port changed to {port}");
+//!     }
+//! }
+//! ```
+//!
+//! Pass `generate_merge` alongside `generate_diff` to generate `merge`, which applies a `{Struct}Diff`. Each field which is `Some` in the diff is stored through the field's setter. Fields which are `None`, and fields without a setter taking the value, such as `read_only` ones, are left unchanged.
+//! ```
+//! use ters::ters;
+//!
+//! #[ters(generate_diff, generate_merge)]
+//! struct Config {
+//!     #[get]
+//!     host: String,
+//!     #[get]
+//!     #[set]
+//!     port: u16,
+//! }
+//!
+//! fn generate_merge(config: &mut Config, edited: &Config) {
+//!     let patch = config.diff(edited);
+//!     config.merge(patch);
+//! }
+//! ```
+//!
+//! With the `std` feature enabled, pass `generate_setters_from_env` to `#[ters]` to generate `set_from_env`. It parses each field which has a setter from the environment variable named after the struct and field in `SCREAMING_SNAKE_CASE`, using `FromStr`, and stores it through the setter. Fields whose variables are not set are left unchanged. Failures are reported with a generated `{Struct}EnvError` naming the variable.
+#![cfg_attr(feature = "std", doc = "```")]
+#![cfg_attr(not(feature = "std"), doc = "```ignore")]
+//! use ters::ters;
+//!
+//! #[ters(generate_setters_from_env)]
+//! struct ServerConfig {
+//!     #[set]
+//!     host: String,
+//!     #[set]
+//!     port: u16,
+//! }
+//!
+//! fn generate_setters_from_env(config: &mut ServerConfig) -> Result<(), ServerConfigEnvError> {
+//!     // reads `SERVER_CONFIG_HOST` and `SERVER_CONFIG_PORT`
+//!     config.set_from_env()
+//! }
+//! ```
+//!
+//! With the `serde` feature enabled, pass `generate_serde_accessor_trait` to `#[ters]` to generate a `{Struct}SerdeAccessors` trait, implemented for the struct. It has a `_json` method for each field which has a getter, serializing the field with `serde_json::to_value`. The feature pulls in `serde` and `serde_json` for the generated code, and the fields' types must implement `Serialize`.
+#![cfg_attr(feature = "serde", doc = "```")]
+#![cfg_attr(not(feature = "serde"), doc = "```ignore")]
+//! use ters::ters;
+//!
+//! #[ters(generate_serde_accessor_trait)]
+//! struct User {
+//!     #[get]
+//!     name: String,
+//!     #[get]
+//!     roles: Vec<String>,
+//! }
+//!
+//! fn generate_serde_accessor_trait(user: &User) {
+//!     println!("{}", user.roles_json().unwrap());
+//! }
+//! ```
+
+#![no_std]
+
+pub use ters_macros::{ters, Getters, Setters};
+
+/// Dependencies of the generated code, which need not be dependencies of the crate using it.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "log")]
+    pub use log;
+    #[cfg(feature = "serde")]
+    pub use serde;
+    #[cfg(feature = "serde")]
+    pub use serde_json;
+}
+
+// at the crate root so generated `::std` paths resolve in tests
+#[cfg(test)]
+extern crate std;
+
+// so generated `::ters` paths resolve in tests
+#[cfg(test)]
+extern crate self as ters;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn getters() {
+        #[ters]
+        struct Foo {
+            #[allow(unused)]
+            a: i32,
+            #[get]
+            b: bool,
+        }
+
+        let foo = Foo { a: 42, b: true };
+        assert_eq!(foo.b(), &true);
+    }
+
+    #[test]
+    fn setters() {
+        #[ters]
+        struct Foo {
+            #[allow(unused)]
+            a: i32,
+            #[get]
+            #[set]
+            b: bool,
+        }
+
+        let mut foo = Foo { a: 42, b: true };
+        assert_eq!(foo.b(), &true);
+        foo.set_b(false);
+
+        assert_eq!(foo.b(), &false);
+    }
+
+    #[test]
+    fn both() {
+        #[ters]
+        struct Foo {
+            #[get]
+            a: i32,
+            #[get]
+            #[set]
+            b: bool,
+        }
+
+        let mut foo = Foo { a: 42, b: true };
+        assert_eq!(foo.a(), &42);
+        assert_eq!(foo.b(), &true);
+        foo.set_b(false);
+
+        assert_eq!(foo.b(), &false);
+    }
+
+    #[test]
+    fn cfgs() {
+        #[ters]
+        struct Foo {
+            #[get]
+            #[set]
+            #[cfg(any())]
+            a: i32,
+            #[get]
+            #[cfg(test)]
+            #[cfg(not(any()))]
+            b: bool,
+        }
+
+        let foo = Foo { b: true };
+        assert_eq!(foo.b(), &true);
+    }
+
+    #[test]
+    fn associated_types() {
+        trait Assoc {
+            type Assoc;
+        }
+
+        impl Assoc for u8 {
+            type Assoc = u16;
+        }
+
+        #[ters]
+        struct Foo<I: Iterator, T: Assoc> {
+            #[get]
+            #[set]
+            a: <I as Iterator>::Item,
+            #[get]
+            #[set]
+            b: T::Assoc,
+            #[allow(unused)]
+            iter: I,
+        }
+
+        let mut foo = Foo::<core::ops::Range<u32>, u8> {
+            a: 1,
+            b: 2,
+            iter: 0..1,
+        };
+        let a: &u32 = foo.a();
+        let b: &u16 = foo.b();
+        assert_eq!(a, &1);
+        assert_eq!(b, &2);
+        foo.set_a(3);
+        foo.set_b(4);
+
+        assert_eq!(foo.a(), &3);
+        assert_eq!(foo.b(), &4);
+    }
+
+    #[test]
+    fn normalize() {
+        fn clamp(value: i32) -> i32 {
+            value.clamp(0, 10)
+        }
+
+        #[ters]
+        struct Foo {
+            #[get]
+            #[set(normalize = clamp)]
+            a: i32,
+        }
+
+        let mut foo = Foo { a: 5 };
+        foo.set_a(42);
+        assert_eq!(foo.a(), &10);
+        foo.set_a(-3);
+
+        assert_eq!(foo.a(), &0);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated() {
+        #[ters]
+        struct Foo {
+            #[get]
+            #[set]
+            #[deprecated(note = "use `b`")]
+            a: i32,
+        }
+
+        let mut foo = Foo { a: 42 };
+        foo.set_a(31);
+
+        assert_eq!(foo.a(), &31);
+    }
+
+    #[test]
+    fn impl_from_tuple() {
+        #[ters(impl_from_tuple)]
+        struct Point(
+            #[get(copy, name = "x")]
+            #[set(name = "set_x")]
+            f32,
+            #[get(copy, name = "y")] f32,
+        );
+
+        let mut point = Point::from((1.0, 2.0));
+        point.set_x(3.0);
+
+        assert_eq!((point.x(), point.y()), (3.0, 2.0));
+        assert_eq!(<(f32, f32)>::from(point), (3.0, 2.0));
+    }
+
+    #[test]
+    fn impl_from_tuple_generic() {
+        #[ters(impl_from_tuple)]
+        struct Pair<T>(T, T);
+
+        let pair = Pair::from(("a", "b"));
+
+        assert_eq!(<(&str, &str)>::from(pair), ("a", "b"));
+    }
+
+    #[test]
+    fn rename_all() {
+        #[ters(get, set, rename_all = "PascalCase")]
+        struct Foo {
+            kind_id: u8,
+            _hidden_value: u8,
+        }
+
+        let mut foo = Foo {
+            kind_id: 1,
+            _hidden_value: 2,
+        };
+        foo.SetKindId(3);
+
+        assert_eq!(foo.KindId(), &3);
+        assert_eq!(foo._HiddenValue(), &2);
+    }
+
+    #[test]
+    fn const_value() {
+        #[ters]
+        struct SizedBuffer<const N: usize> {
+            #[get(const_value = N)]
+            #[allow(unused)]
+            len: usize,
+        }
+
+        const LEN: usize = SizedBuffer::<4>::len();
+        assert_eq!(LEN, 4);
+    }
+
+    #[test]
+    fn atomic_fetch_update() {
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        #[ters]
+        struct Foo {
+            #[set(atomic_fetch_update)]
+            a: AtomicU32,
+        }
+
+        let foo = Foo {
+            a: AtomicU32::new(1),
+        };
+
+        assert_eq!(
+            foo.fetch_update_a(Ordering::SeqCst, Ordering::SeqCst, |a| a.checked_add(1)),
+            Ok(1)
+        );
+        assert_eq!(
+            foo.fetch_update_a(Ordering::SeqCst, Ordering::SeqCst, |_| None),
+            Err(2)
+        );
+    }
+
+    #[test]
+    fn pub_fields() {
+        #[ters]
+        struct Foo {
+            #[get]
+            pub a: u32,
+        }
+
+        let foo = Foo { a: 1 };
+        assert_eq!(foo.a(), &foo.a);
+    }
+
+    #[test]
+    fn impl_display() {
+        use std::format;
+
+        #[ters(impl_display)]
+        struct Foo<T> {
+            #[get]
+            a: T,
+            #[get]
+            b: &'static str,
+            #[allow(unused)]
+            c: (),
+        }
+
+        let foo = Foo {
+            a: 1.5,
+            b: "bar",
+            c: (),
+        };
+        assert_eq!(format!("{foo}"), "Foo { a: 1.5, b: bar }");
+        assert_eq!(format!("{foo:#}"), "Foo {\n    a: 1.5,\n    b: bar,\n}");
+    }
+
+    #[test]
+    fn builder_fn() {
+        #[ters(generate_builder_fn)]
+        struct Foo {
+            #[get]
+            a: i32,
+        }
+
+        #[derive(Default)]
+        struct FooBuilder {
+            a: i32,
+        }
+
+        impl FooBuilder {
+            fn a(self, a: i32) -> Self {
+                Self { a }
+            }
+
+            fn build(self) -> Foo {
+                Foo { a: self.a }
+            }
+        }
+
+        let foo = Foo::builder().a(1).build();
+        assert_eq!(foo.a(), &1);
+    }
+
+    #[test]
+    fn zip_adjacent() {
+        #[ters]
+        struct Vector {
+            #[get(zip_adjacent)]
+            x: f64,
+            #[get(zip_adjacent)]
+            y: f64,
+            z: f64,
+        }
+
+        let v = Vector {
+            x: 1.,
+            y: 2.,
+            z: 3.,
+        };
+        assert_eq!(v.xy(), (&1., &2.));
+        assert_eq!(v.yz(), (&2., &3.));
+    }
+
+    #[test]
+    fn field_name_str() {
+        #[ters]
+        struct Foo {
+            #[get]
+            #[get(field_name_str)]
+            a: i32,
+        }
+
+        let foo = Foo { a: 1 };
+        assert_eq!((foo.a_as_str(), foo.a()), ("a", &1));
+    }
+
+    #[test]
+    fn field_lists() {
+        #[ters(get = [name, age], set = [age])]
+        struct Person {
+            name: &'static str,
+            age: u8,
+            #[allow(unused)]
+            id: u32,
+        }
+
+        let mut person = Person {
+            name: "Ferris",
+            age: 8,
+            id: 0,
+        };
+        person.set_age(9);
+        assert_eq!((person.name(), person.age()), (&"Ferris", &9));
+    }
+
+    #[test]
+    fn all_fields() {
+        #[ters(get, set, exclude = [_cache])]
+        struct Foo {
+            #[get(copy)]
+            a: i32,
+            b: bool,
+            _cache: u8,
+        }
+
+        let mut foo = Foo {
+            a: 1,
+            b: false,
+            _cache: 0,
+        };
+        foo.set_a(2);
+        foo.set_b(true);
+        assert_eq!((foo.a(), foo.b()), (2, &true));
+    }
+
+    #[test]
+    fn setter_prefix() {
+        #[ters(setter_prefix = "put_")]
+        struct Foo {
+            #[get]
+            #[set]
+            a: i32,
+        }
+
+        #[ters(setter_prefix = "")]
+        struct Bar {
+            #[set]
+            b: i32,
+        }
+
+        let mut foo = Foo { a: 1 };
+        foo.put_a(2);
+        assert_eq!(foo.a(), &2);
+
+        let mut bar = Bar { b: 1 };
+        bar.b(2);
+        assert_eq!(bar.b, 2);
+    }
+
+    #[test]
+    fn version_2() {
+        mod inner {
+            use super::*;
+
+            #[ters(version = 2, constructor)]
+            pub struct Foo {
+                #[get]
+                a: i32,
+                #[get(vis = "pub")]
+                b: i32,
+            }
+
+            pub fn foo() -> Foo {
+                let foo = Foo::new(1, 2);
+                assert_eq!(foo.a(), &1);
+                foo
+            }
+        }
+
+        assert_eq!(inner::foo().b(), &2);
+    }
+
+    #[test]
+    fn reexports() {
+        mod reexport {
+            pub use crate::ters;
+        }
+
+        #[reexport::ters]
+        #[reexport::ters(constructor)]
+        struct Foo {
+            #[get]
+            a: i32,
+        }
+
+        let foo = Foo::new(1);
+        assert_eq!(foo.a(), &1);
+    }
+
+    #[test]
+    fn conditional_setters() {
+        fn is_even(value: &i32) -> bool {
+            value % 2 == 0
+        }
+
+        #[ters]
+        struct Foo {
+            #[get]
+            #[set(conditional = is_even)]
+            a: i32,
+        }
+
+        let mut foo = Foo { a: 0 };
+        assert!(foo.set_a(2));
+        assert!(!foo.set_a(3));
+        assert_eq!(foo.a(), &2);
+    }
+
+    #[test]
+    fn idempotent_setters() {
+        #[ters]
+        struct Foo {
+            #[get]
+            #[set(idempotent)]
+            a: i32,
+        }
+
+        let mut foo = Foo { a: 0 };
+        assert!(foo.set_a(1));
+        assert!(!foo.set_a(1));
+        assert_eq!(foo.a(), &1);
+    }
+
+    #[test]
+    fn mutable_getters() {
+        #[ters]
+        struct Foo {
+            #[get(mut)]
+            a: i32,
+            #[get(mutable_alias)]
+            b: i32,
+            #[get(with_mut, name = "third")]
+            c: i32,
+            #[get(mut_pair)]
+            d: i32,
+        }
+
+        let mut foo = Foo {
+            a: 1,
+            b: 2,
+            c: 3,
+            d: 4,
+        };
+
+        *foo.a_mut() += 1;
+        *foo.b_mut() += 1;
+        *foo.third_mut() += 1;
+        *foo.d_mut() += 1;
+
+        assert_eq!(foo.a, 2);
+        assert_eq!(foo.b(), &3);
+        assert_eq!(foo.third(), &4);
+        assert_eq!(foo.d(), &5);
+    }
+
+    #[test]
+    fn expect_lints() {
+        // the struct's expectation is not extended to the generated impl
+        #[expect(dead_code)]
+        #[ters]
+        struct Foo {
+            #[get]
+            a: i32,
+        }
+    }
+
+    #[test]
+    fn derive_macros() {
+        #[derive(Getters, Setters)]
+        struct Foo {
+            #[get]
+            #[set]
+            a: i32,
+            #[get(copy)]
+            b: bool,
+        }
+
+        let mut foo = Foo { a: 1, b: true };
+        foo.set_a(2);
+        assert_eq!(foo.a(), &2);
+        assert!(foo.b());
+    }
+
+    #[test]
+    fn const_generics() {
+        #[ters]
+        struct Foo<const N: usize, const M: usize = 2> {
+            #[get]
+            #[set]
+            a: [u8; N],
+            #[get(copy)]
+            b: [u8; M],
+        }
+
+        let mut foo: Foo<3> = Foo {
+            a: [0; 3],
+            b: [1; 2],
+        };
+        foo.set_a([1, 2, 3]);
+
+        assert_eq!(foo.a(), &[1, 2, 3]);
+        assert_eq!(foo.b(), [1, 1]);
+    }
+
+    #[test]
+    fn generic_associated_types() {
+        trait Container {
+            type Iter<'a>: Iterator<Item = &'a u8>
+            where
+                Self: 'a;
+
+            fn iter(&self) -> Self::Iter<'_>;
+        }
+
+        impl Container for [u8; 2] {
+            type Iter<'a> = core::slice::Iter<'a, u8>;
+
+            fn iter(&self) -> Self::Iter<'_> {
+                self.as_slice().iter()
+            }
+        }
+
+        #[ters]
+        struct Foo<'a, C>
+        where
+            C: Container + 'a,
+            for<'b> C::Iter<'b>: Clone,
+        {
+            #[get]
+            #[set]
+            a: C,
+            #[get]
+            b: C::Iter<'a>,
+        }
+
+        let c = [5, 6];
+        let mut foo = Foo {
+            a: [1, 2],
+            b: c.iter(),
+        };
+        assert_eq!(foo.a().iter().sum::<u8>(), 3);
+        assert_eq!(foo.b().clone().sum::<u8>(), 11);
+        foo.set_a([3, 4]);
+
+        assert_eq!(foo.a().iter().sum::<u8>(), 7);
+    }
+
+    #[test]
+    fn propagate_lints() {
+        #[ters(propagate_lints)]
+        struct Foo {
+            #[get]
+            #[deny(non_snake_case)]
+            a: i32,
+        }
+
+        let foo = Foo { a: 42 };
+
+        assert_eq!(foo.a(), &42);
+    }
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn shadowed_struct_name() {
+        #[ters]
+        struct Foo {
+            #[get]
+            a: i32,
+        }
+
+        let Foo = Foo { a: 42 };
+
+        assert_eq!(Foo.a(), &42);
+    }
+
+    #[test]
+    fn format_with() {
+        use core::fmt::Write;
+
+        struct Hex<'a>(&'a [u8]);
+
+        impl core::fmt::Display for Hex<'_> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+            }
+        }
+
+        fn hex(bytes: &[u8; 2]) -> Hex<'_> {
+            Hex(bytes)
+        }
+
+        #[ters]
+        struct Foo {
+            #[get(format_with = hex)]
+            a: [u8; 2],
+        }
+
+        struct Buf([u8; 4], usize);
+
+        impl Write for Buf {
+            fn write_str(&mut self, s: &str) -> core::fmt::Result {
+                self.0[self.1..self.1 + s.len()].copy_from_slice(s.as_bytes());
+                self.1 += s.len();
+                Ok(())
+            }
+        }
+
+        let foo = Foo { a: [0xbe, 0xef] };
+        let mut buf = Buf([0; 4], 0);
+        write!(buf, "{}", foo.a_display()).unwrap();
+
+        assert_eq!(&buf.0, b"beef");
+    }
+
+    #[test]
+    fn restricted_struct_visibility() {
+        mod inner {
+            use super::*;
+
+            #[ters]
+            pub(crate) struct Foo {
+                #[get]
+                pub(super) a: i32,
+            }
+        }
+
+        let foo = inner::Foo { a: 42 };
+
+        assert_eq!(foo.a(), &42);
+    }
+
+    #[test]
+    fn reexported_struct() {
+        mod inner {
+            mod nested {
+                use crate::ters;
+
+                #[ters]
+                pub struct Foo {
+                    #[get]
+                    a: i32,
+                    #[get(vis = "pub(super)")]
+                    b: i32,
+                    #[get(vis = "pub(crate)")]
+                    c: i32,
+                }
+
+                impl Foo {
+                    pub fn new() -> Self {
+                        Self { a: 1, b: 2, c: 3 }
+                    }
+                }
+            }
+
+            pub use nested::Foo;
+
+            // `pub(super)` is relative to where the method is defined, not re-exported
+            pub fn b(foo: &Foo) -> i32 {
+                *foo.b()
+            }
+        }
+
+        let foo = inner::Foo::new();
+
+        assert_eq!(foo.a(), &1);
+        assert_eq!(inner::b(&foo), 2);
+        assert_eq!(foo.c(), &3);
+    }
+
+    #[test]
+    fn merge_applications() {
+        #[ters]
+        #[cfg_attr(test, ters(propagate_lints))]
+        struct Foo {
+            #[get]
+            #[deny(non_snake_case)]
+            a: i32,
+        }
+
+        let foo = Foo { a: 42 };
+
+        assert_eq!(foo.a(), &42);
+    }
+
+    #[test]
+    fn on_read() {
+        use core::cell::Cell;
+
+        std::thread_local! {
+            static READS: Cell<usize> = const { Cell::new(0) };
+            static LAST: Cell<&'static str> = const { Cell::new("") };
+        }
+
+        fn count() {
+            READS.with(|reads| reads.set(reads.get() + 1));
+        }
+
+        #[ters]
+        struct Foo {
+            #[get(on_read = count)]
+            a: i32,
+            #[get(on_read = |name| LAST.with(|last| last.set(name)))]
+            b: bool,
+        }
+
+        let foo = Foo { a: 42, b: true };
+        assert_eq!(foo.a(), &42);
+        assert_eq!(foo.a(), &42);
+        assert_eq!(READS.with(Cell::get), 2);
+        assert_eq!(foo.b(), &true);
+
+        assert_eq!(LAST.with(Cell::get), "b");
+    }
+
+    #[test]
+    fn rename() {
+        #[ters]
+        struct Foo {
+            #[get(name = "value")]
+            #[get]
+            #[set(name = "replace_a")]
+            a: i32,
+            #[get(name = "_é")]
+            b: bool,
+        }
+
+        let mut foo = Foo { a: 42, b: true };
+        assert_eq!(foo.value(), &42);
+        foo.replace_a(31);
+        assert_eq!(foo.a(), &31);
+
+        assert_eq!(foo._é(), &true);
+    }
+
+    #[test]
+    fn builder() {
+        #[ters]
+        struct Foo {
+            #[get]
+            #[set(builder)]
+            a: i32,
+            #[get]
+            #[set]
+            #[set(builder)]
+            b: bool,
+        }
+
+        let foo = Foo { a: 42, b: true }.with_a(31).with_b(false);
+        assert_eq!(foo.a(), &31);
+
+        assert_eq!(foo.b(), &false);
+    }
+
+    #[test]
+    fn copy_and_clone() {
+        #[derive(Clone, Debug, PartialEq)]
+        struct Bar(i32);
+
+        #[ters]
+        struct Foo<T: Copy> {
+            #[get(copy)]
+            a: i32,
+            #[get(clone)]
+            b: Bar,
+            #[get(copy)]
+            c: T,
+        }
+
+        let foo = Foo {
+            a: 42,
+            b: Bar(31),
+            c: true,
+        };
+        let a: i32 = foo.a();
+        let b: Bar = foo.b();
+        assert_eq!(a, 42);
+        assert_eq!(b, Bar(31));
+
+        assert!(foo.c());
+    }
+
+    #[test]
+    fn opaque() {
+        mod inner {
+            use super::*;
+
+            struct Secret(i32);
+
+            #[ters]
+            pub struct Foo {
+                #[get(opaque)]
+                a: Secret,
+            }
+
+            impl Foo {
+                pub fn new() -> Self {
+                    Self { a: Secret(42) }
+                }
+            }
+
+            pub fn reveal(any: &dyn core::any::Any) -> Option<i32> {
+                any.downcast_ref::<Secret>().map(|secret| secret.0)
+            }
+        }
+
+        let foo = inner::Foo::new();
+
+        assert_eq!(inner::reveal(foo.a()), Some(42));
+    }
+
+    #[test]
+    fn matches() {
+        #[allow(unused)]
+        enum State {
+            Idle,
+            Connecting { attempt: u8 },
+            Connected { peer: u16 },
+            Closed,
+        }
+
+        #[ters]
+        struct Conn {
+            #[get(matches = State::Connected { .. }, name = "is_connected")]
+            #[get(matches = State::Idle | State::Closed, name = "is_inactive")]
+            state: State,
+        }
+
+        let conn = Conn {
+            state: State::Connected { peer: 1 },
+        };
+        assert!(conn.is_connected());
+        assert!(!conn.is_inactive());
+
+        let conn = Conn {
+            state: State::Closed,
+        };
+        assert!(!conn.is_connected());
+        assert!(conn.is_inactive());
+    }
+
+    #[test]
+    fn sized() {
+        #[ters]
+        struct Foo<T: ?Sized> {
+            #[get(sized)]
+            a: i32,
+            b: T,
+        }
+
+        let foo = Foo { a: 42, b: [0u8; 2] };
+        assert_eq!(foo.a(), &42);
+
+        let unsized_foo: &Foo<[u8]> = &foo;
+        assert_eq!(unsized_foo.b.len(), 2);
+    }
+
+    #[test]
+    fn debug_assert_some() {
+        #[ters]
+        struct Foo {
+            #[get(debug_assert_some)]
+            a: Option<u32>,
+        }
+
+        let foo = Foo { a: Some(42) };
+
+        assert_eq!(foo.a(), Some(&42));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "`a` expected to be `Some`")]
+    fn debug_assert_some_none() {
+        #[ters]
+        struct Foo {
+            #[get(debug_assert_some)]
+            a: Option<u32>,
+        }
+
+        let foo = Foo { a: None };
+        let _ = foo.a();
+    }
+
+    #[test]
+    fn generate_index() {
+        #[ters(generate_index)]
+        struct Rgb {
+            #[get]
+            red: u8,
+            #[get]
+            green: u8,
+            blue: u8,
+        }
+
+        let rgb = Rgb {
+            red: 1,
+            green: 2,
+            blue: 3,
+        };
+
+        assert_eq!(rgb[RgbField::Red], 1);
+        assert_eq!(rgb[RgbField::Green], 2);
+        assert_eq!(rgb.blue, 3);
+    }
+
+    #[test]
+    fn generate_mask() {
+        #[ters(generate_mask)]
+        struct Flags {
+            #[get(copy)]
+            read: bool,
+            #[get(copy)]
+            write: bool,
+            #[cfg(any())]
+            hidden: bool,
+            #[get(copy)]
+            exec: bool,
+        }
+
+        // the configured-out field keeps its bit, the fourth
+        let flags = Flags::from_u8(0b1001);
+        assert!(flags.read() && !flags.write() && flags.exec());
+        assert_eq!(flags.to_u8(), 0b1001);
+
+        assert_eq!(Flags::from_u8(0b1111_1010).to_u8(), 0b1010);
+    }
+
+    #[test]
+    fn try_from_int() {
+        #[ters(try_from_int)]
+        #[derive(Debug, PartialEq)]
+        enum Opcode {
+            Nop,
+            Load = 4,
+            Store,
+        }
+
+        assert_eq!(Opcode::try_from(0), Ok(Opcode::Nop));
+        assert_eq!(Opcode::try_from(5), Ok(Opcode::Store));
+        assert_eq!(Opcode::try_from(1), Err(OpcodeTryFromIntError(1)));
+
+        #[ters(try_from_int = u8)]
+        #[derive(Debug, PartialEq)]
+        #[repr(i16)]
+        enum Wide {
+            Negative = -1,
+            Large = 300,
+            Small = 44,
+        }
+
+        // discriminants are not truncated to the integer type
+        assert_eq!(Wide::try_from(44), Ok(Wide::Small));
+        assert_eq!(Wide::try_from(255), Err(WideTryFromIntError(255)));
+        assert_eq!(
+            std::format!("{}", Wide::try_from(1).unwrap_err()),
+            "1 is not the discriminant of a `Wide` variant"
+        );
+    }
+
+    #[test]
+    fn generate_update_fn() {
+        use std::string::String;
+
+        #[ters(generate_update_fn)]
+        struct Profile {
+            #[set]
+            name: String,
+            #[set]
+            age: u8,
+            id: u32,
+        }
+
+        let mut profile = Profile {
+            name: "Ferris".into(),
+            age: 8,
+            id: 1,
+        };
+        profile.update(&Profile {
+            name: "Corro".into(),
+            age: 3,
+            id: 2,
+        });
+
+        assert_eq!(
+            (profile.name.as_str(), profile.age, profile.id),
+            ("Corro", 3, 1)
+        );
+    }
+
+    #[test]
+    fn generate_snapshot() {
+        use std::{string::String, vec::Vec};
+
+        #[ters(generate_snapshot)]
+        struct Inventory {
+            #[get]
+            items: Vec<String>,
+            #[get]
+            #[set]
+            capacity: usize,
+            scratch: u8,
+        }
+
+        let mut inventory = Inventory {
+            items: ["sword".into()].into(),
+            capacity: 4,
+            scratch: 0,
+        };
+        let before = inventory.snapshot();
+        inventory.set_capacity(8);
+
+        assert_ne!(inventory.snapshot(), before);
+        assert_eq!(
+            before.clone(),
+            InventorySnapshot {
+                items: ["sword".into()].into(),
+                capacity: 4,
+            }
+        );
+        assert_eq!(inventory.scratch, 0);
+    }
+
+    #[test]
+    fn generate_diff() {
+        use std::string::String;
+
+        #[ters(generate_diff)]
+        struct Config {
+            #[get]
+            host: String,
+            #[get]
+            port: u16,
+        }
+
+        let old = Config {
+            host: "localhost".into(),
+            port: 80,
+        };
+        let new = Config {
+            host: "localhost".into(),
+            port: 8080,
+        };
+        let diff = old.diff(&new);
+
+        assert_eq!((diff.host, diff.port), (None, Some(8080)));
+    }
+
+    #[test]
+    fn generate_merge() {
+        use std::string::String;
+
+        fn clamp(port: u16) -> u16 {
+            port.max(1024)
+        }
+
+        #[ters(generate_diff, generate_merge)]
+        #[derive(Clone)]
+        struct Config {
+            #[get(read_only)]
+            id: u32,
+            #[get]
+            #[set]
+            host: String,
+            #[get]
+            #[set(normalize = clamp)]
+            port: u16,
+        }
+
+        let mut config = Config {
+            id: 1,
+            host: "localhost".into(),
+            port: 8080,
+        };
+        let mut edited = config.clone();
+        edited.id = 2;
+        edited.host = "example.com".into();
+        edited.port = 80;
+
+        let patch = config.diff(&edited);
+        assert_eq!(patch.id, Some(2));
+        config.merge(patch);
+
+        // the read-only field is left unchanged
+        assert_eq!(
+            (config.id(), config.host().as_str(), config.port()),
+            (&1, "example.com", &1024)
+        );
+    }
+
+    #[test]
+    fn result_accessors() {
+        #[ters]
+        struct Foo {
+            #[get(ok)]
+            #[get(err)]
+            #[get(is_ok)]
+            a: Result<i32, &'static str>,
+        }
+
+        let foo = Foo { a: Ok(42) };
+        assert_eq!(foo.a_ok(), Some(&42));
+        assert_eq!(foo.a_err(), None);
+        assert!(foo.a_is_ok());
+
+        let foo = Foo { a: Err("oops") };
+        assert_eq!(foo.a_ok(), None);
+        assert_eq!(foo.a_err(), Some(&"oops"));
+        assert!(!foo.a_is_ok());
+    }
+
+    #[test]
+    fn unpack() {
+        #[ters]
+        struct Foo {
+            #[get(copy)]
+            #[set(unpack)]
+            pos: (f32, f32),
+            #[get]
+            #[set(unpack(start, end), builder)]
+            range: (u32, u32),
+        }
+
+        let mut foo = Foo {
+            pos: (0., 0.),
+            range: (0, 0),
+        };
+        foo.set_pos(1., 2.);
+        assert_eq!(foo.pos(), (1., 2.));
+
+        let foo = foo.with_range(3, 4);
+        assert_eq!(foo.range(), &(3, 4));
+    }
+
+    #[test]
+    fn thiserror() {
+        use std::string::{String, ToString};
+
+        #[ters]
+        #[derive(Debug, thiserror::Error)]
+        #[error("unexpected input at {position}")]
+        struct ParseError {
+            #[get]
+            input: String,
+            #[get(copy)]
+            position: usize,
+        }
+
+        let e = ParseError {
+            input: "1 + + 2".into(),
+            position: 4,
+        };
+
+        assert_eq!(e.to_string(), "unexpected input at 4");
+        assert_eq!((e.input().as_str(), e.position()), ("1 + + 2", 4));
+    }
+
+    #[test]
+    fn derives() {
+        use std::{
+            collections::hash_map::DefaultHasher,
+            format,
+            hash::{Hash, Hasher},
+            string::String,
+        };
+
+        fn hash(value: &impl Hash) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        #[derive(Default, Clone, Debug, serde::Serialize)]
+        #[ters]
+        #[derive(PartialEq, Eq, Hash, serde::Deserialize)]
+        struct Foo {
+            #[get]
+            #[set]
+            a: i32,
+            #[get]
+            b: String,
+        }
+
+        let mut foo = Foo::default();
+        assert_eq!(foo.a(), &0);
+        assert_eq!(foo.b(), "");
+
+        foo.set_a(42);
+        let bar = foo.clone();
+        assert_eq!(foo, bar);
+        assert_eq!(hash(&foo), hash(&bar));
+        assert_eq!(format!("{bar:?}"), r#"Foo { a: 42, b: "" }"#);
+
+        let json = serde_json::to_string(&bar).unwrap();
+        assert_eq!(json, r#"{"a":42,"b":""}"#);
+        assert_eq!(serde_json::from_str::<Foo>(&json).unwrap(), bar);
+    }
+
+    #[test]
+    fn constructor() {
+        #[ters(constructor, skip_none)]
+        struct Foo {
+            #[get]
+            a: i32,
+            #[get]
+            b: Option<i32>,
+            #[get]
+            #[include_in_constructor]
+            c: Option<i32>,
+        }
+
+        let foo = Foo::new(1, Some(3));
+        assert_eq!(foo.a(), &1);
+        assert_eq!(foo.b(), &None);
+        assert_eq!(foo.c(), &Some(3));
+    }
+
+    #[ters]
+    struct Bounded {
+        #[get]
+        #[set(bounded = "0.0..=1.0")]
+        a: f32,
+    }
+
+    #[test]
+    fn bounded() {
+        let mut foo = Bounded { a: 0. };
+        foo.set_a(1.);
+        assert_eq!(foo.a(), &1.);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic = "`a` must be within `0.0..=1.0`, got 1.5"]
+    fn bounded_out_of_range() {
+        Bounded { a: 0. }.set_a(1.5);
+    }
+
+    #[test]
+    fn discriminant() {
+        use core::mem::discriminant;
+
+        #[allow(unused)]
+        enum State {
+            Idle,
+            Busy(u8),
+        }
+
+        #[ters]
+        struct Foo {
+            #[get(discriminant)]
+            a: State,
+        }
+
+        let foo = Foo { a: State::Busy(1) };
+
+        assert_eq!(foo.a_discriminant(), discriminant(&State::Busy(2)));
+        assert_ne!(foo.a_discriminant(), discriminant(&State::Idle));
+    }
+
+    #[test]
+    fn passthrough() {
+        #[ters(passthrough)]
+        struct Foo {
+            #[get]
+            #[set]
+            a: i32,
+        }
+
+        impl Foo {
+            fn a(&self) -> i32 {
+                self.a * 2
+            }
+        }
+
+        assert_eq!(Foo { a: 21 }.a(), 42);
+    }
+
+    #[test]
+    fn into_fields() {
+        use std::string::String;
+
+        #[ters(into_fields)]
+        struct Foo {
+            #[get]
+            a: i32,
+            #[allow(unused)]
+            b: i32,
+            #[get]
+            c: String,
+        }
+
+        let FooFields { a, c } = Foo {
+            a: 1,
+            b: 2,
+            c: "3".into(),
+        }
+        .into();
+
+        assert_eq!(a, 1);
+        assert_eq!(c, "3");
+    }
+
+    #[test]
+    fn transmute_to() {
+        #[ters]
+        struct Foo {
+            #[get(transmute_to = f32)]
+            #[get(transmute_to = [u8; 4], name = "a_bytes")]
+            a: u32,
+        }
+
+        let foo = Foo {
+            a: 1.5f32.to_bits(),
+        };
+
+        assert_eq!(unsafe { foo.a_as_f32() }, 1.5);
+        assert_eq!(unsafe { foo.a_bytes() }, 1.5f32.to_ne_bytes());
+    }
+
+    #[test]
+    fn transmute_to_generic() {
+        #[ters]
+        struct Foo<T: Copy> {
+            #[get(transmute_to = u32)]
+            a: T,
+        }
+
+        let foo = Foo { a: 1.5f32 };
+
+        assert_eq!(unsafe { foo.a_as_u32() }, 1.5f32.to_bits());
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn fn_ptr_setters() {
+        #[ters]
+        struct Foo {
+            #[set(fn_ptr)]
+            a: i32,
+        }
+
+        let mut foo = Foo { a: 1 };
+        let set: unsafe extern "C" fn(*mut Foo, i32) = Foo::A_SETTER;
+        unsafe { set(&mut foo, 2) };
+        assert_eq!(foo.a, 2);
+    }
+
+    #[cfg(feature = "ffi")]
+    #[test]
+    fn fn_ptr_getters() {
+        #[ters]
+        struct Foo {
+            #[get(fn_ptr)]
+            #[set(fn_ptr)]
+            a: i32,
+        }
+
+        // together, a C-compatible vtable for the field
+        #[repr(C)]
+        struct VTable {
+            get: unsafe extern "C" fn(*const Foo) -> i32,
+            set: unsafe extern "C" fn(*mut Foo, i32),
+        }
+
+        let vtable = VTable {
+            get: Foo::A_GETTER,
+            set: Foo::A_SETTER,
+        };
+
+        let mut foo = Foo { a: 1 };
+        unsafe {
+            (vtable.set)(&mut foo, 2);
+            assert_eq!((vtable.get)(&foo), 2);
+        }
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn log_changes() {
+        #[ters]
+        struct Foo {
+            #[get]
+            #[set(log_changes)]
+            a: i32,
+        }
+
+        let mut foo = Foo { a: 0 };
+        foo.set_a(42);
+        assert_eq!(*foo.a(), 42);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn generate_serde_accessor_trait() {
+        #[ters(generate_serde_accessor_trait)]
+        struct Foo {
+            #[get]
+            a: i32,
+            #[get]
+            b: [bool; 2],
+        }
+
+        let foo = Foo {
+            a: 1,
+            b: [true, false],
+        };
+        assert_eq!(foo.a_json().unwrap(), 1);
+        assert_eq!(foo.b_json().unwrap()[1], false);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn generate_setters_from_env() {
+        use std::string::{String, ToString};
+
+        #[ters(generate_setters_from_env)]
+        struct ServerConfig {
+            #[set]
+            host: String,
+            #[set]
+            port: u16,
+            #[set]
+            workers: u8,
+        }
+
+        let mut config = ServerConfig {
+            host: "localhost".into(),
+            port: 80,
+            workers: 1,
+        };
+
+        std::env::set_var("SERVER_CONFIG_HOST", "example.com");
+        std::env::set_var("SERVER_CONFIG_PORT", "8080");
+        std::env::remove_var("SERVER_CONFIG_WORKERS");
+        config.set_from_env().unwrap();
+
+        assert_eq!(
+            (config.host.as_str(), config.port, config.workers),
+            ("example.com", 8080, 1)
+        );
+
+        std::env::set_var("SERVER_CONFIG_WORKERS", "many");
+
+        assert_eq!(
+            config.set_from_env().unwrap_err().to_string(),
+            "`SERVER_CONFIG_WORKERS` could not be parsed from `many`"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn generate_setters_from_env_names() {
+        #[ters(generate_setters_from_env)]
+        struct HTTPConfig {
+            #[set(name = "set_type")]
+            r#type: u8,
+        }
+
+        let mut config = HTTPConfig { r#type: 0 };
+
+        std::env::set_var("HTTP_CONFIG_TYPE", "2");
+        config.set_from_env().unwrap();
+
+        assert_eq!(config.r#type, 2);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn debug_str() {
+        #[ters]
+        struct Foo {
+            #[get(debug_str)]
+            a: Option<&'static str>,
+        }
+
+        let foo = Foo { a: Some("bar") };
+        assert_eq!(foo.a_debug_str(), "Some(\"bar\")");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn arc_ptr() {
+        use std::sync::Arc;
+
+        #[ters]
+        struct Foo {
+            #[get(arc_ptr)]
+            a: Arc<i32>,
+        }
+
+        let a = Arc::new(42);
+        let foo = Foo { a: Arc::clone(&a) };
+
+        assert_eq!(foo.a_ptr(), Arc::as_ptr(&a));
+        assert_eq!(unsafe { *foo.a_ptr() }, 42);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn push() {
+        use std::collections::VecDeque;
+
+        #[ters]
+        struct Foo {
+            #[get]
+            #[set(push_back)]
+            #[set(push_front)]
+            a: VecDeque<i32>,
+        }
+
+        let mut foo = Foo { a: VecDeque::new() };
+        foo.push_back_a(2);
+        foo.push_front_a(1);
+        foo.push_back_a(3);
+
+        assert_eq!(foo.a(), &[1, 2, 3]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn pop() {
+        use std::collections::VecDeque;
+
+        #[ters]
+        struct Foo {
+            #[get(pop_front)]
+            #[get(pop_back)]
+            a: VecDeque<i32>,
+        }
+
+        let mut foo = Foo {
+            a: VecDeque::from([1, 2, 3]),
+        };
+
+        assert_eq!(foo.pop_front_a(), Some(1));
+        assert_eq!(foo.pop_back_a(), Some(3));
+        assert_eq!(foo.pop_back_a(), Some(2));
+        assert_eq!(foo.pop_front_a(), None);
+    }
+
+    #[test]
+    fn hash_value() {
+        use core::hash::Hasher;
+
+        /// Sums the written bytes, so hashes are easy to predict.
+        #[derive(Default)]
+        struct SumHasher(u64);
+
+        impl Hasher for SumHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                self.0 += bytes.iter().map(|&b| u64::from(b)).sum::<u64>();
+            }
+        }
+
+        #[ters]
+        struct Foo {
+            #[get(hash_value = SumHasher)]
+            a: [u8; 3],
+        }
+
+        let foo = Foo { a: [1, 2, 3] };
+
+        // the length prefix of 3 plus the elements
+        assert_eq!(foo.a_hash(), 3 + 1 + 2 + 3);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn default_hash_value() {
+        #[ters]
+        struct Foo {
+            #[get(hash_value)]
+            a: i32,
+        }
+
+        assert_eq!(Foo { a: 1 }.a_hash(), Foo { a: 1 }.a_hash());
+        assert_ne!(Foo { a: 1 }.a_hash(), Foo { a: 2 }.a_hash());
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_fn() {
+        use core::{
+            future::Future,
+            pin::pin,
+            task::{Context, Poll, Waker},
+        };
+
+        #[ters]
+        struct Foo {
+            #[get(async_fn)]
+            a: i32,
+        }
+
+        let foo = Foo { a: 42 };
+        let future = pin!(foo.a());
+
+        assert_eq!(
+            future.poll(&mut Context::from_waker(Waker::noop())),
+            Poll::Ready(&42)
+        );
     }
 }